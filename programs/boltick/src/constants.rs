@@ -1,6 +1,10 @@
 use anchor_lang::prelude::*;
 
 pub const ANCHOR_DISCRIMINATOR: usize = 8;
+pub const MAX_BPS: u16 = 10_000;
+pub const MAX_CREATORS: usize = 5;
+pub const MAX_BUYER_TIERS: usize = 16;
+pub const SETTLEMENT_DISPUTE_WINDOW: i64 = 3 * 24 * 60 * 60; // 3 days
 
 #[constant]
 pub const SEED_CONFIG: &[u8] = b"config";
@@ -22,3 +26,30 @@ pub const SEED_TOKEN_MINT: &[u8] = b"token_mint";
 
 #[constant]
 pub const SEED_DIGITAL_ACCESS: &[u8] = b"digital_access";
+
+#[constant]
+pub const SEED_EVENT_ROLE: &[u8] = b"event_role";
+
+#[constant]
+pub const SEED_REDEMPTION: &[u8] = b"redemption";
+
+#[constant]
+pub const SEED_VAULT: &[u8] = b"vault";
+
+#[constant]
+pub const SEED_TICKET: &[u8] = b"ticket";
+
+#[constant]
+pub const SEED_LISTING: &[u8] = b"listing";
+
+#[constant]
+pub const SEED_PRESALE_CLAIM: &[u8] = b"presale_claim";
+
+#[constant]
+pub const SEED_BUYER_RECORD: &[u8] = b"buyer_record";
+
+#[constant]
+pub const SEED_DISCOUNT: &[u8] = b"discount";
+
+#[constant]
+pub const SEED_WAITLIST_ENTRY: &[u8] = b"waitlist_entry";
@@ -8,6 +8,128 @@ pub enum DappError {
     InvalidCreator,
     #[msg("The digital access has reached its max supply")]
     MaxSupplyReached,
-    // #[msg("The event is not active")]
-    // EventNotActive,
+    #[msg("The signer is not allowed to redeem tickets for this event")]
+    InvalidScanner,
+    #[msg("The ticket has already been redeemed")]
+    TicketAlreadyRedeemed,
+    #[msg("The provided basis points exceed the maximum allowed")]
+    InvalidBasisPoints,
+    #[msg("The provided amount is zero")]
+    ZeroAmount,
+    #[msg("The treasury does not have enough funds")]
+    InsufficientTreasuryFunds,
+    #[msg("Arithmetic overflow occurred during the operation")]
+    ArithmeticOverflow,
+    #[msg("The payment mint does not match the digital access payment mint")]
+    PaymentMintMismatch,
+    #[msg("The token accounts required to pay with the payment mint are missing")]
+    MissingPaymentAccounts,
+    #[msg("The provided schedule is invalid")]
+    InvalidSchedule,
+    #[msg("The digital access is not on sale")]
+    SaleNotActive,
+    #[msg("The event status does not allow this operation")]
+    InvalidEventStatus,
+    #[msg("The event proceeds are not held in escrow")]
+    EventNotEscrowed,
+    #[msg("The token account does not hold the ticket")]
+    TicketNotOwned,
+    #[msg("The event proceeds cannot be settled yet")]
+    SettlementNotAvailable,
+    #[msg("The creators must be at most five and their shares must add up to 100")]
+    InvalidCreators,
+    #[msg("The operation is not supported for digital access paid with a payment mint")]
+    UnsupportedPaymentMint,
+    #[msg("The resale price exceeds the maximum allowed for the digital access")]
+    ResalePriceTooHigh,
+    #[msg("The creator accounts do not match the event creators")]
+    InvalidCreatorAccounts,
+    #[msg("The destination must sign to receive a non-transferable ticket")]
+    DestinationMustSign,
+    #[msg("The event is not active")]
+    EventNotActive,
+    #[msg("The max supply cannot be lower than the tokens already minted")]
+    InvalidMaxSupply,
+    #[msg("The digital access is closed")]
+    DigitalAccessClosed,
+    #[msg("The signer is not the pending authority")]
+    InvalidPendingAuthority,
+    #[msg("The program is paused")]
+    ProgramPaused,
+    #[msg("The event is paused")]
+    EventPaused,
+    #[msg("The presale proof is invalid")]
+    InvalidPresaleProof,
+    #[msg("The presale allocation has already been claimed")]
+    PresaleAllocationExceeded,
+    #[msg("The presale claim account is required during the presale")]
+    MissingPresaleClaim,
+    #[msg("The wallet purchase limit has been reached")]
+    PurchaseLimitExceeded,
+    #[msg("The buyer record account is required when a purchase limit is set")]
+    MissingBuyerRecord,
+    #[msg("The buyer record cannot track more digital access types")]
+    BuyerRecordFull,
+    #[msg("The batch accounts do not match the tokens to mint")]
+    InvalidBatchAccounts,
+    #[msg("The event mints compressed tickets")]
+    CompressedEvent,
+    #[msg("The event does not mint compressed tickets")]
+    NotCompressedEvent,
+    #[msg("Compressed tickets are not supported for escrowed events")]
+    CompressedEscrowNotSupported,
+    #[msg("The digital access is not supported for compressed tickets")]
+    CompressedTierNotSupported,
+    #[msg("The event mints token-2022 tickets")]
+    Token2022Event,
+    #[msg("The event does not mint token-2022 tickets")]
+    NotToken2022Event,
+    #[msg("The digital access is not supported for token-2022 tickets")]
+    Token2022TierNotSupported,
+    #[msg("The ticket cannot be burned until the event has ended")]
+    TicketNotBurnable,
+    #[msg("The event cannot be closed yet")]
+    EventNotClosable,
+    #[msg("The digital access accounts do not match the event")]
+    InvalidDigitalAccessAccounts,
+    #[msg("The signer does not have the required event role")]
+    MissingEventRole,
+    #[msg("The discount amount is invalid")]
+    InvalidDiscount,
+    #[msg("The discount code is invalid")]
+    InvalidDiscountCode,
+    #[msg("The discount is expired, used up or restricted to another digital access")]
+    DiscountNotApplicable,
+    #[msg("The pricing mode is invalid")]
+    InvalidPricing,
+    #[msg("The price exceeds the maximum price accepted by the buyer")]
+    PriceAboveMax,
+    #[msg("Dutch auctions are only supported for escrowed events")]
+    AuctionRequiresEscrow,
+    #[msg("The auction is still running")]
+    AuctionNotOver,
+    #[msg("There is no rebate for this ticket")]
+    NoRebateAvailable,
+    #[msg("The digital access is not sold out")]
+    NotSoldOut,
+    #[msg("The digital access does not support a waitlist")]
+    WaitlistNotSupported,
+    #[msg("Buyers on the waitlist have priority")]
+    WaitlistNotEmpty,
+    #[msg("The skipped waitlist accounts are invalid")]
+    InvalidWaitlistAccounts,
+    #[msg("The ticket cannot be upgraded to this digital access")]
+    InvalidUpgrade,
+    #[msg("Auction pricing cannot be switched on or off once tokens are sold")]
+    AuctionPricingLocked,
+    #[msg("Tickets of the event have not been burned yet")]
+    TicketsOutstanding,
+    #[msg("The vault token accounts are invalid")]
+    InvalidVaultTokenAccounts,
+    #[msg("Auction rebates are still held back for their buyers")]
+    RebatesOutstanding,
+    #[msg("The supply of an auction cannot change once tokens are sold or the auction is over")]
+    AuctionSupplyLocked,
+    #[msg("Tickets can only be refunded once the event is cancelled or to make room for the waitlist")]
+    RefundNotAvailable,
 }
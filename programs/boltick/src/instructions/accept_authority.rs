@@ -0,0 +1,25 @@
+use anchor_lang::prelude::*;
+
+use crate::{Config, DappError, SEED_CONFIG};
+
+#[derive(Accounts)]
+pub struct AcceptAuthority<'info> {
+    pub pending_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED_CONFIG],
+        bump = config.bump,
+        constraint = config.pending_authority == Some(pending_authority.key()) @ DappError::InvalidPendingAuthority,
+    )]
+    pub config: Account<'info, Config>,
+}
+
+pub fn process_accept_authority(ctx: Context<AcceptAuthority>) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+
+    config.authority = ctx.accounts.pending_authority.key();
+    config.pending_authority = None;
+
+    Ok(())
+}
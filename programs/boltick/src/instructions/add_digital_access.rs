@@ -1,26 +1,42 @@
 use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
 
 use crate::{
-    DappError, DigitalAccess, Event, ANCHOR_DISCRIMINATOR, SEED_DIGITAL_ACCESS, SEED_EVENT,
+    Config, DappError, DigitalAccess, Event, EventRole, PricingMode, Role, ANCHOR_DISCRIMINATOR,
+    SEED_CONFIG, SEED_DIGITAL_ACCESS, SEED_EVENT, SEED_EVENT_ROLE,
 };
 
 #[derive(Accounts)]
 #[instruction(event_id: u64)]
 pub struct AddDigitalAccess<'info> {
     #[account(mut)]
-    pub creator: Signer<'info>,
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_CONFIG],
+        bump = config.bump,
+        constraint = !config.paused @ DappError::ProgramPaused,
+    )]
+    pub config: Account<'info, Config>,
 
     #[account(
         mut,
         seeds = [SEED_EVENT, event_id.to_le_bytes().as_ref()],
         bump = event.bump,
-        has_one = creator @ DappError::InvalidCreator,
+        constraint = !event.paused @ DappError::EventPaused,
     )]
     pub event: Account<'info, Event>,
 
+    // only required when the signer is not the event creator
+    #[account(
+        seeds = [SEED_EVENT_ROLE, event.key().as_ref(), signer.key().as_ref()],
+        bump = event_role.bump,
+    )]
+    pub event_role: Option<Account<'info, EventRole>>,
+
     #[account(
         init,
-        payer = creator,
+        payer = signer,
         space = DigitalAccess::INIT_SPACE + ANCHOR_DISCRIMINATOR,
         seeds = [
             SEED_DIGITAL_ACCESS,
@@ -31,9 +47,28 @@ pub struct AddDigitalAccess<'info> {
     )]
     pub digital_access: Account<'info, DigitalAccess>,
 
+    // paid in native SOL when not provided
+    pub payment_mint: Option<InterfaceAccount<'info, Mint>>,
+
     pub system_program: Program<'info, System>,
 }
 
+impl<'info> AddDigitalAccess<'info> {
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.event.has_role(
+                &self.signer.key(),
+                self.event_role.as_deref(),
+                Role::Manager
+            ),
+            DappError::MissingEventRole
+        );
+
+        Ok(())
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
 pub fn process_add_digital_access(
     ctx: Context<AddDigitalAccess>,
     _event_id: u64,
@@ -43,13 +78,47 @@ pub fn process_add_digital_access(
     symbol: String,
     description: String,
     uri: String,
+    sale_start: Option<i64>,
+    sale_end: Option<i64>,
+    max_resale_bps: Option<u16>,
+    transferable: bool,
+    max_per_wallet: Option<u64>,
 ) -> Result<()> {
+    ctx.accounts.validate()?;
+
+    require!(
+        DigitalAccess::is_valid_sale_window(sale_start, sale_end),
+        DappError::InvalidSchedule
+    );
+    // compressed tickets cannot be frozen
+    require!(
+        transferable || ctx.accounts.event.merkle_tree.is_none(),
+        DappError::CompressedTierNotSupported
+    );
+
     ctx.accounts.digital_access.set_inner(DigitalAccess {
         event: ctx.accounts.event.key(),
         id: ctx.accounts.event.current_digital_access_count,
         price,
+        pricing: PricingMode::Flat,
+        clearing_price: 0,
+        unclaimed_bids: 0,
+        unclaimed_bidders: 0,
+        payment_mint: ctx.accounts.payment_mint.as_ref().map(|mint| mint.key()),
         max_supply,
         current_minted: 0,
+        waitlist_head: 0,
+        waitlist_tail: 0,
+        waitlist_len: 0,
+        sale_start,
+        sale_end,
+        max_resale_bps,
+        transferable,
+        closed: false,
+        max_per_wallet,
+        presale_root: None,
+        presale_start: None,
+        presale_end: None,
         name,
         symbol,
         description,
@@ -0,0 +1,384 @@
+#[allow(deprecated)]
+use anchor_lang::solana_program::keccak;
+use anchor_lang::{
+    prelude::*,
+    solana_program::{instruction::Instruction, program::invoke_signed, pubkey},
+};
+
+use crate::EventCreator;
+
+// the mpl-bubblegum crate does not build against this anchor version, so the CPIs are built by hand
+pub const BUBBLEGUM_PROGRAM_ID: Pubkey = pubkey!("BGUMAp9Gq7iTEuizy4pqaxsTyUCBK68MDfK752saRPUY");
+pub const ACCOUNT_COMPRESSION_PROGRAM_ID: Pubkey =
+    pubkey!("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK");
+pub const NOOP_PROGRAM_ID: Pubkey = pubkey!("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV");
+
+const CREATE_TREE_DISCRIMINATOR: [u8; 8] = [165, 83, 136, 142, 89, 202, 47, 220];
+const MINT_TO_COLLECTION_V1_DISCRIMINATOR: [u8; 8] = [153, 18, 178, 47, 197, 158, 86, 15];
+const UPDATE_METADATA_DISCRIMINATOR: [u8; 8] = [170, 182, 43, 239, 97, 78, 225, 186];
+const VERIFY_LEAF_DISCRIMINATOR: [u8; 8] = [124, 220, 22, 223, 104, 10, 250, 224];
+
+// offset of num_minted in the bubblegum TreeConfig account
+const TREE_CONFIG_NUM_MINTED_OFFSET: usize = 8 + 32 + 32 + 8;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub enum BubblegumTokenStandard {
+    NonFungible,
+    FungibleAsset,
+    Fungible,
+    NonFungibleEdition,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub enum BubblegumTokenProgramVersion {
+    Original,
+    Token2022,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub enum BubblegumUseMethod {
+    Burn,
+    Multiple,
+    Single,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub struct BubblegumUses {
+    pub use_method: BubblegumUseMethod,
+    pub remaining: u64,
+    pub total: u64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub struct BubblegumCollection {
+    pub verified: bool,
+    pub key: Pubkey,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub struct BubblegumCreator {
+    pub address: Pubkey,
+    pub verified: bool,
+    pub share: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub struct BubblegumMetadataArgs {
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+    pub seller_fee_basis_points: u16,
+    pub primary_sale_happened: bool,
+    pub is_mutable: bool,
+    pub edition_nonce: Option<u8>,
+    pub token_standard: Option<BubblegumTokenStandard>,
+    pub collection: Option<BubblegumCollection>,
+    pub uses: Option<BubblegumUses>,
+    pub token_program_version: BubblegumTokenProgramVersion,
+    pub creators: Vec<BubblegumCreator>,
+}
+
+#[derive(AnchorSerialize)]
+struct BubblegumUpdateArgs {
+    name: Option<String>,
+    symbol: Option<String>,
+    uri: Option<String>,
+    creators: Option<Vec<BubblegumCreator>>,
+    seller_fee_basis_points: Option<u16>,
+    primary_sale_happened: Option<bool>,
+    is_mutable: Option<bool>,
+}
+
+pub fn compressed_creators(creators: &[EventCreator], authority: Pubkey) -> Vec<BubblegumCreator> {
+    // same creators as the uncompressed tickets (see metadata_creators)
+    if creators.is_empty() {
+        return vec![BubblegumCreator {
+            address: authority,
+            verified: true,
+            share: 100,
+        }];
+    }
+
+    creators
+        .iter()
+        .map(|creator| BubblegumCreator {
+            address: creator.address,
+            verified: creator.address == authority,
+            share: creator.share,
+        })
+        .collect()
+}
+
+pub fn tree_num_minted(tree_authority: &AccountInfo) -> Result<u64> {
+    let data = tree_authority.try_borrow_data()?;
+    let bytes = data
+        .get(TREE_CONFIG_NUM_MINTED_OFFSET..TREE_CONFIG_NUM_MINTED_OFFSET + 8)
+        .ok_or(ErrorCode::AccountDidNotDeserialize)?;
+
+    Ok(u64::from_le_bytes(bytes.try_into().unwrap()))
+}
+
+pub fn compressed_asset_id(merkle_tree: &Pubkey, nonce: u64) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"asset", merkle_tree.as_ref(), &nonce.to_le_bytes()],
+        &BUBBLEGUM_PROGRAM_ID,
+    )
+    .0
+}
+
+// bubblegum LeafSchema::V1 hash
+pub fn compressed_leaf_hash(
+    asset_id: &Pubkey,
+    owner: &Pubkey,
+    delegate: &Pubkey,
+    nonce: u64,
+    data_hash: &[u8; 32],
+    creator_hash: &[u8; 32],
+) -> [u8; 32] {
+    keccak::hashv(&[
+        &[1],
+        asset_id.as_ref(),
+        owner.as_ref(),
+        delegate.as_ref(),
+        &nonce.to_le_bytes(),
+        data_hash,
+        creator_hash,
+    ])
+    .to_bytes()
+}
+
+fn invoke_program<'info>(
+    program_id: Pubkey,
+    accounts: Vec<AccountMeta>,
+    data: Vec<u8>,
+    account_infos: &[AccountInfo<'info>],
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    invoke_signed(
+        &Instruction {
+            program_id,
+            accounts,
+            data,
+        },
+        account_infos,
+        signer_seeds,
+    )
+    .map_err(Into::into)
+}
+
+fn proof_metas(proof: &[AccountInfo]) -> Vec<AccountMeta> {
+    proof
+        .iter()
+        .map(|node| AccountMeta::new_readonly(node.key(), false))
+        .collect()
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn create_tree<'info>(
+    bubblegum_program: &AccountInfo<'info>,
+    tree_authority: &AccountInfo<'info>,
+    merkle_tree: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    tree_creator: &AccountInfo<'info>,
+    log_wrapper: &AccountInfo<'info>,
+    compression_program: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    max_depth: u32,
+    max_buffer_size: u32,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let mut data = CREATE_TREE_DISCRIMINATOR.to_vec();
+    (max_depth, max_buffer_size, Some(false)).serialize(&mut data)?;
+
+    invoke_program(
+        bubblegum_program.key(),
+        vec![
+            AccountMeta::new(tree_authority.key(), false),
+            AccountMeta::new(merkle_tree.key(), false),
+            AccountMeta::new(payer.key(), true),
+            AccountMeta::new_readonly(tree_creator.key(), true),
+            AccountMeta::new_readonly(log_wrapper.key(), false),
+            AccountMeta::new_readonly(compression_program.key(), false),
+            AccountMeta::new_readonly(system_program.key(), false),
+        ],
+        data,
+        &[
+            tree_authority.clone(),
+            merkle_tree.clone(),
+            payer.clone(),
+            tree_creator.clone(),
+            log_wrapper.clone(),
+            compression_program.clone(),
+            system_program.clone(),
+        ],
+        signer_seeds,
+    )
+}
+
+pub struct MintToCollectionV1Accounts<'a, 'info> {
+    pub bubblegum_program: &'a AccountInfo<'info>,
+    pub tree_authority: &'a AccountInfo<'info>,
+    pub leaf_owner: &'a AccountInfo<'info>,
+    pub merkle_tree: &'a AccountInfo<'info>,
+    pub payer: &'a AccountInfo<'info>,
+    // the collection mint is tree creator and collection update authority
+    pub collection_mint: &'a AccountInfo<'info>,
+    pub collection_metadata: &'a AccountInfo<'info>,
+    pub collection_master_edition: &'a AccountInfo<'info>,
+    pub bubblegum_signer: &'a AccountInfo<'info>,
+    pub log_wrapper: &'a AccountInfo<'info>,
+    pub compression_program: &'a AccountInfo<'info>,
+    pub token_metadata_program: &'a AccountInfo<'info>,
+    pub system_program: &'a AccountInfo<'info>,
+}
+
+pub fn mint_to_collection_v1<'info>(
+    accounts: MintToCollectionV1Accounts<'_, 'info>,
+    metadata: BubblegumMetadataArgs,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let mut data = MINT_TO_COLLECTION_V1_DISCRIMINATOR.to_vec();
+    metadata.serialize(&mut data)?;
+
+    invoke_program(
+        accounts.bubblegum_program.key(),
+        vec![
+            AccountMeta::new(accounts.tree_authority.key(), false),
+            AccountMeta::new_readonly(accounts.leaf_owner.key(), false),
+            AccountMeta::new_readonly(accounts.leaf_owner.key(), false),
+            AccountMeta::new(accounts.merkle_tree.key(), false),
+            AccountMeta::new(accounts.payer.key(), true),
+            AccountMeta::new_readonly(accounts.collection_mint.key(), true),
+            AccountMeta::new_readonly(accounts.collection_mint.key(), true),
+            // no collection authority record
+            AccountMeta::new_readonly(accounts.bubblegum_program.key(), false),
+            AccountMeta::new_readonly(accounts.collection_mint.key(), false),
+            AccountMeta::new(accounts.collection_metadata.key(), false),
+            AccountMeta::new_readonly(accounts.collection_master_edition.key(), false),
+            AccountMeta::new_readonly(accounts.bubblegum_signer.key(), false),
+            AccountMeta::new_readonly(accounts.log_wrapper.key(), false),
+            AccountMeta::new_readonly(accounts.compression_program.key(), false),
+            AccountMeta::new_readonly(accounts.token_metadata_program.key(), false),
+            AccountMeta::new_readonly(accounts.system_program.key(), false),
+        ],
+        data,
+        &[
+            accounts.tree_authority.clone(),
+            accounts.leaf_owner.clone(),
+            accounts.merkle_tree.clone(),
+            accounts.payer.clone(),
+            accounts.collection_mint.clone(),
+            accounts.bubblegum_program.clone(),
+            accounts.collection_metadata.clone(),
+            accounts.collection_master_edition.clone(),
+            accounts.bubblegum_signer.clone(),
+            accounts.log_wrapper.clone(),
+            accounts.compression_program.clone(),
+            accounts.token_metadata_program.clone(),
+            accounts.system_program.clone(),
+        ],
+        signer_seeds,
+    )
+}
+
+pub struct UpdateMetadataAccounts<'a, 'info> {
+    pub bubblegum_program: &'a AccountInfo<'info>,
+    pub tree_authority: &'a AccountInfo<'info>,
+    pub collection_mint: &'a AccountInfo<'info>,
+    pub collection_metadata: &'a AccountInfo<'info>,
+    pub leaf_owner: &'a AccountInfo<'info>,
+    pub leaf_delegate: &'a AccountInfo<'info>,
+    pub payer: &'a AccountInfo<'info>,
+    pub merkle_tree: &'a AccountInfo<'info>,
+    pub log_wrapper: &'a AccountInfo<'info>,
+    pub compression_program: &'a AccountInfo<'info>,
+    pub token_metadata_program: &'a AccountInfo<'info>,
+    pub system_program: &'a AccountInfo<'info>,
+    pub proof: &'a [AccountInfo<'info>],
+}
+
+pub fn update_compressed_uri<'info>(
+    accounts: UpdateMetadataAccounts<'_, 'info>,
+    root: [u8; 32],
+    nonce: u64,
+    index: u32,
+    current_metadata: BubblegumMetadataArgs,
+    uri: String,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let mut data = UPDATE_METADATA_DISCRIMINATOR.to_vec();
+    (root, nonce, index, current_metadata).serialize(&mut data)?;
+    BubblegumUpdateArgs {
+        name: None,
+        symbol: None,
+        uri: Some(uri),
+        creators: None,
+        seller_fee_basis_points: None,
+        primary_sale_happened: None,
+        is_mutable: None,
+    }
+    .serialize(&mut data)?;
+
+    let mut metas = vec![
+        AccountMeta::new_readonly(accounts.tree_authority.key(), false),
+        // items of a verified collection are updated by the collection authority
+        AccountMeta::new_readonly(accounts.collection_mint.key(), true),
+        AccountMeta::new_readonly(accounts.collection_mint.key(), false),
+        AccountMeta::new_readonly(accounts.collection_metadata.key(), false),
+        // no collection authority record
+        AccountMeta::new_readonly(accounts.bubblegum_program.key(), false),
+        AccountMeta::new_readonly(accounts.leaf_owner.key(), false),
+        AccountMeta::new_readonly(accounts.leaf_delegate.key(), false),
+        AccountMeta::new(accounts.payer.key(), true),
+        AccountMeta::new(accounts.merkle_tree.key(), false),
+        AccountMeta::new_readonly(accounts.log_wrapper.key(), false),
+        AccountMeta::new_readonly(accounts.compression_program.key(), false),
+        AccountMeta::new_readonly(accounts.token_metadata_program.key(), false),
+        AccountMeta::new_readonly(accounts.system_program.key(), false),
+    ];
+    metas.extend(proof_metas(accounts.proof));
+
+    let mut account_infos = vec![
+        accounts.tree_authority.clone(),
+        accounts.collection_mint.clone(),
+        accounts.collection_metadata.clone(),
+        accounts.bubblegum_program.clone(),
+        accounts.leaf_owner.clone(),
+        accounts.leaf_delegate.clone(),
+        accounts.payer.clone(),
+        accounts.merkle_tree.clone(),
+        accounts.log_wrapper.clone(),
+        accounts.compression_program.clone(),
+        accounts.token_metadata_program.clone(),
+        accounts.system_program.clone(),
+    ];
+    account_infos.extend_from_slice(accounts.proof);
+
+    invoke_program(
+        accounts.bubblegum_program.key(),
+        metas,
+        data,
+        &account_infos,
+        signer_seeds,
+    )
+}
+
+pub fn verify_leaf<'info>(
+    compression_program: &AccountInfo<'info>,
+    merkle_tree: &AccountInfo<'info>,
+    proof: &[AccountInfo<'info>],
+    root: [u8; 32],
+    leaf: [u8; 32],
+    index: u32,
+) -> Result<()> {
+    let mut data = VERIFY_LEAF_DISCRIMINATOR.to_vec();
+    (root, leaf, index).serialize(&mut data)?;
+
+    let mut metas = vec![AccountMeta::new_readonly(merkle_tree.key(), false)];
+    metas.extend(proof_metas(proof));
+
+    let mut account_infos = vec![merkle_tree.clone()];
+    account_infos.extend_from_slice(proof);
+
+    invoke_program(compression_program.key(), metas, data, &account_infos, &[])
+}
@@ -0,0 +1,205 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    metadata::Metadata,
+    token_interface::{self, Mint, TokenAccount, TokenInterface},
+};
+
+use crate::{
+    DappError, DigitalAccess, Event, Ticket, SEED_COLLECTION_MINT, SEED_EVENT, SEED_TICKET,
+    SEED_TOKEN_MINT,
+};
+
+use super::{burn_nft, thaw_token_account};
+
+#[derive(Accounts)]
+#[instruction(event_id: u64, token_id: u64)]
+pub struct BurnTicket<'info> {
+    #[account(mut)]
+    pub holder: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED_EVENT, event_id.to_le_bytes().as_ref()],
+        bump = event.bump,
+    )]
+    pub event: Box<Account<'info, Event>>,
+
+    #[account(mut, has_one = event)]
+    pub digital_access: Box<Account<'info, DigitalAccess>>,
+
+    #[account(
+        mut,
+        close = holder,
+        seeds = [SEED_TICKET, token_mint.key().as_ref()],
+        bump = ticket.bump,
+        has_one = event,
+        has_one = digital_access,
+    )]
+    pub ticket: Box<Account<'info, Ticket>>,
+
+    #[account(
+        mut,
+        seeds = [
+            SEED_TOKEN_MINT,
+            event.collection_mint_account.as_ref(),
+            token_id.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = holder,
+        associated_token::token_program = token_program,
+        constraint = holder_token_account.amount == 1 @ DappError::TicketNotOwned,
+    )]
+    pub holder_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        seeds = [SEED_COLLECTION_MINT, event_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub collection_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    // metaplex accounts, left untouched when the event mints token-2022 tickets
+    /// CHECK: Validate address by deriving pda
+    #[account(
+        mut,
+        seeds = [
+            b"metadata",
+            token_metadata_program.key().as_ref(),
+            token_mint.key().as_ref()
+        ],
+        bump,
+        seeds::program = token_metadata_program.key(),
+    )]
+    pub metadata_account: UncheckedAccount<'info>,
+
+    /// CHECK: Validate address by deriving pda
+    #[account(
+        mut,
+        seeds = [
+            b"metadata",
+            token_metadata_program.key().as_ref(),
+            token_mint.key().as_ref(),
+            b"edition"
+        ],
+        bump,
+        seeds::program = token_metadata_program.key(),
+    )]
+    pub edition_account: UncheckedAccount<'info>,
+
+    /// CHECK: Validate address by deriving pda
+    #[account(
+        mut,
+        seeds = [
+            b"metadata",
+            token_metadata_program.key().as_ref(),
+            event.collection_mint_account.as_ref()
+        ],
+        bump,
+        seeds::program = token_metadata_program.key(),
+    )]
+    pub collection_metadata_account: UncheckedAccount<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub token_metadata_program: Program<'info, Metadata>,
+}
+
+impl<'info> BurnTicket<'info> {
+    pub fn burn_token_2022(&self, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+        if self.holder_token_account.is_frozen() {
+            token_interface::thaw_account(CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                token_interface::ThawAccount {
+                    account: self.holder_token_account.to_account_info(),
+                    mint: self.token_mint.to_account_info(),
+                    authority: self.collection_mint.to_account_info(),
+                },
+                signer_seeds,
+            ))?;
+        }
+
+        token_interface::burn(
+            CpiContext::new(
+                self.token_program.to_account_info(),
+                token_interface::Burn {
+                    mint: self.token_mint.to_account_info(),
+                    from: self.holder_token_account.to_account_info(),
+                    authority: self.holder.to_account_info(),
+                },
+            ),
+            1,
+        )?;
+
+        // the mint has no close authority, only the token account rent comes back
+        token_interface::close_account(CpiContext::new(
+            self.token_program.to_account_info(),
+            token_interface::CloseAccount {
+                account: self.holder_token_account.to_account_info(),
+                destination: self.holder.to_account_info(),
+                authority: self.holder.to_account_info(),
+            },
+        ))
+    }
+
+    pub fn burn_metaplex(&self, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+        // soulbound tickets have to be thawed before they can be burned
+        if self.holder_token_account.is_frozen() {
+            thaw_token_account(
+                &self.token_metadata_program,
+                &self.holder_token_account,
+                &self.token_mint,
+                &self.edition_account,
+                &self.collection_mint,
+                &self.token_program,
+                signer_seeds,
+            )?;
+        }
+
+        // burning a verified item also decrements the sized collection
+        burn_nft(
+            &self.token_metadata_program,
+            &self.metadata_account,
+            &self.holder,
+            &self.token_mint,
+            &self.holder_token_account,
+            &self.edition_account,
+            &self.token_program,
+            &self.collection_metadata_account,
+        )
+    }
+}
+
+pub fn process_burn_ticket(ctx: Context<BurnTicket>, event_id: u64, _token_id: u64) -> Result<()> {
+    require!(
+        ctx.accounts
+            .event
+            .is_ticket_burnable(Clock::get()?.unix_timestamp),
+        DappError::TicketNotBurnable
+    );
+
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        SEED_COLLECTION_MINT,
+        &event_id.to_le_bytes(),
+        &[ctx.bumps.collection_mint],
+    ]];
+
+    if ctx.accounts.event.token_2022 {
+        ctx.accounts.burn_token_2022(signer_seeds)?;
+    } else {
+        ctx.accounts.burn_metaplex(signer_seeds)?;
+    }
+
+    ctx.accounts.event.open_tickets -= 1;
+    ctx.accounts
+        .digital_access
+        .forfeit_rebate(&ctx.accounts.ticket);
+    ctx.accounts
+        .digital_access
+        .release_seat(&ctx.accounts.event, Clock::get()?.unix_timestamp);
+
+    Ok(())
+}
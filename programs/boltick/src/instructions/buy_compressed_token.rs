@@ -0,0 +1,244 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{metadata::Metadata, token_interface::Mint};
+
+use crate::{
+    Config, DappError, DigitalAccess, Event, SEED_COLLECTION_MINT, SEED_CONFIG,
+    SEED_DIGITAL_ACCESS, SEED_EVENT, SEED_TREASURY,
+};
+
+use super::{
+    compressed_creators, mint_to_collection_v1, transfer_lamports, BubblegumCollection,
+    BubblegumMetadataArgs, BubblegumTokenProgramVersion, BubblegumTokenStandard,
+    MintToCollectionV1Accounts, ACCOUNT_COMPRESSION_PROGRAM_ID, BUBBLEGUM_PROGRAM_ID,
+    NOOP_PROGRAM_ID,
+};
+
+#[derive(Accounts)]
+#[instruction(event_id: u64, digital_access_id: u8)]
+pub struct BuyCompressedToken<'info> {
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_CONFIG],
+        bump = config.bump,
+        constraint = !config.paused @ DappError::ProgramPaused,
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        mut,
+        seeds = [SEED_EVENT, event_id.to_le_bytes().as_ref()],
+        bump = event.bump,
+        constraint = event.collection_mint_account == collection_mint.key(),
+        constraint = event.merkle_tree == Some(merkle_tree.key()) @ DappError::NotCompressedEvent,
+        constraint = !event.paused @ DappError::EventPaused,
+    )]
+    pub event: Box<Account<'info, Event>>,
+
+    #[account(mut, address = event.creator)]
+    pub event_creator: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            SEED_DIGITAL_ACCESS,
+            event.key().as_ref(),
+            digital_access_id.to_le_bytes().as_ref()
+        ],
+        bump = digital_access.bump,
+        has_one = event,
+        constraint = digital_access.max_supply > digital_access.current_minted @ DappError::MaxSupplyReached,
+    )]
+    pub digital_access: Box<Account<'info, DigitalAccess>>,
+
+    #[account(
+        mut,
+        seeds = [SEED_TREASURY],
+        bump = config.treasury_bump
+    )]
+    pub treasury: SystemAccount<'info>,
+
+    #[account(
+        seeds = [SEED_COLLECTION_MINT, event_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub collection_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// CHECK: Validate address by deriving pda
+    #[account(
+        mut,
+        seeds = [
+            b"metadata",
+            token_metadata_program.key().as_ref(),
+            collection_mint.key().as_ref()
+        ],
+        bump,
+        seeds::program = token_metadata_program.key(),
+    )]
+    pub collection_metadata_account: UncheckedAccount<'info>,
+
+    /// CHECK: Validate address by deriving pda
+    #[account(
+        seeds = [
+            b"metadata",
+            token_metadata_program.key().as_ref(),
+            collection_mint.key().as_ref(),
+            b"edition"
+        ],
+        bump,
+        seeds::program = token_metadata_program.key(),
+    )]
+    pub collection_master_edition: UncheckedAccount<'info>,
+
+    /// CHECK: checked against the event and modified by the account compression program
+    #[account(mut)]
+    pub merkle_tree: UncheckedAccount<'info>,
+
+    /// CHECK: Validate address by deriving pda
+    #[account(
+        mut,
+        seeds = [merkle_tree.key().as_ref()],
+        bump,
+        seeds::program = bubblegum_program.key(),
+    )]
+    pub tree_authority: UncheckedAccount<'info>,
+
+    /// CHECK: Validate address by deriving pda
+    #[account(
+        seeds = [b"collection_cpi"],
+        bump,
+        seeds::program = bubblegum_program.key(),
+    )]
+    pub bubblegum_signer: UncheckedAccount<'info>,
+
+    /// CHECK: Validate address
+    #[account(address = BUBBLEGUM_PROGRAM_ID)]
+    pub bubblegum_program: UncheckedAccount<'info>,
+
+    /// CHECK: Validate address
+    #[account(address = ACCOUNT_COMPRESSION_PROGRAM_ID)]
+    pub compression_program: UncheckedAccount<'info>,
+
+    /// CHECK: Validate address
+    #[account(address = NOOP_PROGRAM_ID)]
+    pub log_wrapper: UncheckedAccount<'info>,
+
+    pub token_metadata_program: Program<'info, Metadata>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> BuyCompressedToken<'info> {
+    pub fn validate(&self) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+
+        require!(self.event.is_on_sale(now), DappError::EventNotActive);
+        require!(
+            self.digital_access.is_on_sale(now) && !self.digital_access.is_in_presale(now),
+            DappError::SaleNotActive
+        );
+        // compressed tickets are paid in SOL, carry no per-wallet limits and cannot be frozen
+        require!(
+            self.digital_access.payment_mint.is_none(),
+            DappError::UnsupportedPaymentMint
+        );
+        require!(
+            self.event.max_per_wallet.is_none()
+                && self.digital_access.max_per_wallet.is_none()
+                && self.digital_access.transferable,
+            DappError::CompressedTierNotSupported
+        );
+
+        Ok(())
+    }
+
+    pub fn collect_payment(&self, price: u64) -> Result<()> {
+        let fee = self.config.protocol_fee(price)?;
+
+        // transfer protocol fee to treasury
+        if fee > 0 {
+            transfer_lamports(
+                &self.system_program,
+                &self.buyer.to_account_info(),
+                &self.treasury.to_account_info(),
+                fee,
+                &[],
+            )?;
+        }
+
+        // transfer SOL to event creator
+        transfer_lamports(
+            &self.system_program,
+            &self.buyer.to_account_info(),
+            &self.event_creator.to_account_info(),
+            price - fee,
+            &[],
+        )
+    }
+}
+
+pub fn process_buy_compressed_token(
+    ctx: Context<BuyCompressedToken>,
+    event_id: u64,
+    _digital_access_id: u8,
+    max_price: u64,
+) -> Result<()> {
+    ctx.accounts.validate()?;
+    let price = ctx
+        .accounts
+        .digital_access
+        .current_price(Clock::get()?.unix_timestamp)?;
+    require!(price <= max_price, DappError::PriceAboveMax);
+    ctx.accounts.collect_payment(price)?;
+
+    let acc = &ctx.accounts;
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        SEED_COLLECTION_MINT,
+        &event_id.to_le_bytes(),
+        &[ctx.bumps.collection_mint],
+    ]];
+
+    mint_to_collection_v1(
+        MintToCollectionV1Accounts {
+            bubblegum_program: &acc.bubblegum_program,
+            tree_authority: &acc.tree_authority,
+            leaf_owner: &acc.buyer,
+            merkle_tree: &acc.merkle_tree,
+            payer: &acc.buyer,
+            collection_mint: &acc.collection_mint.to_account_info(),
+            collection_metadata: &acc.collection_metadata_account,
+            collection_master_edition: &acc.collection_master_edition,
+            bubblegum_signer: &acc.bubblegum_signer,
+            log_wrapper: &acc.log_wrapper,
+            compression_program: &acc.compression_program,
+            token_metadata_program: &acc.token_metadata_program,
+            system_program: &acc.system_program,
+        },
+        BubblegumMetadataArgs {
+            name: format!(
+                "{} #{}",
+                acc.digital_access.name, acc.event.current_nft_count
+            ),
+            symbol: acc.digital_access.symbol.clone(),
+            uri: acc.digital_access.uri.clone(),
+            seller_fee_basis_points: acc.event.seller_fee_basis_points,
+            primary_sale_happened: false,
+            is_mutable: true,
+            edition_nonce: None,
+            token_standard: Some(BubblegumTokenStandard::NonFungible),
+            collection: Some(BubblegumCollection {
+                verified: false,
+                key: acc.collection_mint.key(),
+            }),
+            uses: None,
+            token_program_version: BubblegumTokenProgramVersion::Original,
+            creators: compressed_creators(&acc.event.creators, acc.collection_mint.key()),
+        },
+        signer_seeds,
+    )?;
+
+    ctx.accounts.event.current_nft_count += 1;
+    ctx.accounts.digital_access.current_minted += 1;
+
+    Ok(())
+}
@@ -0,0 +1,188 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+use crate::{
+    Config, DappError, Event, Listing, SEED_CONFIG, SEED_LISTING, SEED_REDEMPTION, SEED_TREASURY,
+};
+
+use super::{close_token_account, transfer_lamports, transfer_tokens};
+
+#[derive(Accounts)]
+pub struct BuyListing<'info> {
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+
+    #[account(mut, address = listing.seller)]
+    pub seller: SystemAccount<'info>,
+
+    #[account(
+        seeds = [SEED_CONFIG],
+        bump = config.bump,
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        mut,
+        seeds = [SEED_TREASURY],
+        bump = config.treasury_bump
+    )]
+    pub treasury: SystemAccount<'info>,
+
+    #[account(address = listing.event)]
+    pub event: Box<Account<'info, Event>>,
+
+    #[account(mut, address = event.creator)]
+    pub event_creator: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        close = seller,
+        seeds = [SEED_LISTING, token_mint.key().as_ref()],
+        bump = listing.bump,
+        has_one = token_mint,
+    )]
+    pub listing: Box<Account<'info, Listing>>,
+
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// CHECK: Validate address by deriving pda, a listed ticket can still be redeemed by staff
+    #[account(
+        seeds = [SEED_REDEMPTION, token_mint.key().as_ref()],
+        bump,
+        constraint = redemption.data_is_empty() @ DappError::TicketAlreadyRedeemed,
+    )]
+    pub redemption: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = listing,
+        associated_token::token_program = token_program,
+    )]
+    pub escrow_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = buyer,
+        associated_token::mint = token_mint,
+        associated_token::authority = buyer,
+        associated_token::token_program = token_program,
+    )]
+    pub buyer_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    // remaining accounts: the event creators, in the same order as `event.creators`
+}
+
+impl<'info> BuyListing<'info> {
+    pub fn pay_royalties(&self, creator_accounts: &[AccountInfo<'info>]) -> Result<u64> {
+        let royalty = self.event.royalty(self.listing.price)?;
+        if royalty == 0 {
+            return Ok(0);
+        }
+
+        // without creators the royalties go to the event creator
+        if self.event.creators.is_empty() {
+            transfer_lamports(
+                &self.system_program,
+                &self.buyer.to_account_info(),
+                &self.event_creator.to_account_info(),
+                royalty,
+                &[],
+            )?;
+
+            return Ok(royalty);
+        }
+
+        require!(
+            creator_accounts.len() == self.event.creators.len(),
+            DappError::InvalidCreatorAccounts
+        );
+
+        let mut paid = 0;
+        for (creator, account) in self.event.creators.iter().zip(creator_accounts) {
+            require_keys_eq!(
+                creator.address,
+                account.key(),
+                DappError::InvalidCreatorAccounts
+            );
+
+            let amount = royalty * creator.share as u64 / 100;
+            if amount > 0 {
+                transfer_lamports(
+                    &self.system_program,
+                    &self.buyer.to_account_info(),
+                    account,
+                    amount,
+                    &[],
+                )?;
+            }
+
+            paid += amount;
+        }
+
+        Ok(paid)
+    }
+
+    pub fn collect_payment(&self, creator_accounts: &[AccountInfo<'info>]) -> Result<()> {
+        let price = self.listing.price;
+        let royalty = self.pay_royalties(creator_accounts)?;
+        let fee = self.config.protocol_fee(price)?;
+
+        // transfer protocol fee to treasury
+        if fee > 0 {
+            transfer_lamports(
+                &self.system_program,
+                &self.buyer.to_account_info(),
+                &self.treasury.to_account_info(),
+                fee,
+                &[],
+            )?;
+        }
+
+        // transfer SOL to seller
+        transfer_lamports(
+            &self.system_program,
+            &self.buyer.to_account_info(),
+            &self.seller.to_account_info(),
+            price
+                .checked_sub(royalty + fee)
+                .ok_or(DappError::ArithmeticOverflow)?,
+            &[],
+        )
+    }
+}
+
+pub fn process_buy_listing<'info>(
+    ctx: Context<'_, '_, '_, 'info, BuyListing<'info>>,
+) -> Result<()> {
+    ctx.accounts.collect_payment(ctx.remaining_accounts)?;
+
+    let acc = &ctx.accounts;
+    let token_mint_key = acc.token_mint.key();
+    let signer_seeds: &[&[&[u8]]] =
+        &[&[SEED_LISTING, token_mint_key.as_ref(), &[acc.listing.bump]]];
+
+    transfer_tokens(
+        &acc.token_program,
+        &acc.escrow_token_account,
+        &acc.buyer_token_account,
+        &acc.listing.to_account_info(),
+        &acc.token_mint,
+        1,
+        signer_seeds,
+    )?;
+
+    close_token_account(
+        &acc.token_program,
+        &acc.escrow_token_account,
+        &acc.seller.to_account_info(),
+        &acc.listing.to_account_info(),
+        signer_seeds,
+    )
+}
@@ -1,13 +1,21 @@
-use anchor_lang::{prelude::*, system_program};
+use anchor_lang::prelude::*;
 use anchor_spl::{
     associated_token::AssociatedToken,
     metadata::{mpl_token_metadata::types::Collection, Metadata},
     token_interface::{Mint, TokenAccount, TokenInterface},
 };
 
-use crate::{Config, DappError, DigitalAccess, Event, SEED_COLLECTION_MINT, SEED_CONFIG, SEED_DIGITAL_ACCESS, SEED_EVENT, SEED_TOKEN_MINT};
+use crate::{
+    BuyerRecord, Config, DappError, DigitalAccess, Discount, Event, PresaleClaim, Ticket,
+    ANCHOR_DISCRIMINATOR, SEED_BUYER_RECORD, SEED_COLLECTION_MINT, SEED_CONFIG,
+    SEED_DIGITAL_ACCESS, SEED_EVENT, SEED_PRESALE_CLAIM, SEED_TICKET, SEED_TOKEN_MINT,
+    SEED_TREASURY, SEED_VAULT,
+};
 
-use super::{create_master_edition, create_metadata_accounts, mint_to, set_and_verify_sized_collection_item};
+use super::{
+    create_master_edition, create_metadata_accounts, freeze_token_account, mint_to,
+    set_and_verify_sized_collection_item, transfer_lamports, transfer_tokens,
+};
 
 #[derive(Accounts)]
 #[instruction(event_id: u64, digital_access_id: u8)]
@@ -18,6 +26,7 @@ pub struct BuyToken<'info> {
     #[account(
         seeds = [SEED_CONFIG],
         bump = config.bump,
+        constraint = !config.paused @ DappError::ProgramPaused,
     )]
     pub config: Account<'info, Config>,
 
@@ -26,6 +35,7 @@ pub struct BuyToken<'info> {
         seeds = [SEED_EVENT, event_id.to_le_bytes().as_ref()],
         bump = event.bump,
         constraint = event.collection_mint_account == collection_mint.key(),
+        constraint = !event.paused @ DappError::EventPaused,
     )]
     pub event: Account<'info, Event>,
 
@@ -45,12 +55,19 @@ pub struct BuyToken<'info> {
     )]
     pub digital_access: Account<'info, DigitalAccess>,
 
-    // #[account(
-    //     mut,
-    //     seeds = [SEED_TREASURY],
-    //     bump = config.treasury_bump
-    // )]
-    // pub treasury: SystemAccount<'info>,
+    #[account(
+        mut,
+        seeds = [SEED_TREASURY],
+        bump = config.treasury_bump
+    )]
+    pub treasury: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED_VAULT, event.key().as_ref()],
+        bump = event.vault_bump
+    )]
+    pub vault: SystemAccount<'info>,
 
     #[account(
         init,
@@ -77,6 +94,15 @@ pub struct BuyToken<'info> {
     )]
     pub buyer_token_account: InterfaceAccount<'info, TokenAccount>,
 
+    #[account(
+        init,
+        payer = buyer,
+        space = Ticket::INIT_SPACE + ANCHOR_DISCRIMINATOR,
+        seeds = [SEED_TICKET, token_mint.key().as_ref()],
+        bump
+    )]
+    pub ticket: Box<Account<'info, Ticket>>,
+
     #[account(
         mut,
         seeds = [SEED_COLLECTION_MINT, event_id.to_le_bytes().as_ref()],
@@ -138,6 +164,72 @@ pub struct BuyToken<'info> {
     )]
     pub collection_master_edition: UncheckedAccount<'info>,
 
+    // payment accounts, only required when the digital access has a payment mint
+    #[account(
+        constraint = digital_access.payment_mint == Some(payment_mint.key()) @ DappError::PaymentMintMismatch,
+        mint::token_program = payment_token_program,
+    )]
+    pub payment_mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+
+    #[account(
+        mut,
+        associated_token::mint = payment_mint,
+        associated_token::authority = buyer,
+        associated_token::token_program = payment_token_program,
+    )]
+    pub buyer_payment_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(
+        mut,
+        associated_token::mint = payment_mint,
+        associated_token::authority = event_creator,
+        associated_token::token_program = payment_token_program,
+    )]
+    pub creator_payment_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(
+        mut,
+        associated_token::mint = payment_mint,
+        associated_token::authority = treasury,
+        associated_token::token_program = payment_token_program,
+    )]
+    pub treasury_payment_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(
+        init_if_needed,
+        payer = buyer,
+        associated_token::mint = payment_mint,
+        associated_token::authority = vault,
+        associated_token::token_program = payment_token_program,
+    )]
+    pub vault_payment_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    pub payment_token_program: Option<Interface<'info, TokenInterface>>,
+
+    // only required while the digital access is in presale
+    #[account(
+        init_if_needed,
+        payer = buyer,
+        space = PresaleClaim::INIT_SPACE + ANCHOR_DISCRIMINATOR,
+        seeds = [SEED_PRESALE_CLAIM, digital_access.key().as_ref(), buyer.key().as_ref()],
+        bump
+    )]
+    pub presale_claim: Option<Box<Account<'info, PresaleClaim>>>,
+
+    // only required when the event or the digital access has a max_per_wallet limit
+    #[account(
+        init_if_needed,
+        payer = buyer,
+        space = BuyerRecord::INIT_SPACE + ANCHOR_DISCRIMINATOR,
+        seeds = [SEED_BUYER_RECORD, event.key().as_ref(), buyer.key().as_ref()],
+        bump
+    )]
+    pub buyer_record: Option<Box<Account<'info, BuyerRecord>>>,
+
+    // only required when purchasing with a discount code
+    #[account(mut, has_one = event)]
+    pub discount: Option<Box<Account<'info, Discount>>>,
+
     pub token_program: Interface<'info, TokenInterface>,
     pub token_metadata_program: Program<'info, Metadata>,
     pub associated_token_program: Program<'info, AssociatedToken>,
@@ -145,22 +237,236 @@ pub struct BuyToken<'info> {
     pub rent: Sysvar<'info, Rent>,
 }
 
-pub fn process_buy_token(ctx: Context<BuyToken>, event_id: u64, _digital_access_id: u8) -> Result<()> {
-    let acc = &ctx.accounts;
+impl<'info> BuyToken<'info> {
+    pub fn validate(&self) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+
+        require!(self.event.is_on_sale(now), DappError::EventNotActive);
+        require!(self.event.merkle_tree.is_none(), DappError::CompressedEvent);
+        require!(!self.event.token_2022, DappError::Token2022Event);
+        require!(
+            self.digital_access.is_on_sale(now) || self.digital_access.is_in_presale(now),
+            DappError::SaleNotActive
+        );
+        require!(
+            self.digital_access.waitlist_len == 0,
+            DappError::WaitlistNotEmpty
+        );
+
+        Ok(())
+    }
+
+    pub fn claim_presale(
+        &mut self,
+        proof: &[[u8; 32]],
+        allocation: Option<u64>,
+        bump: Option<u8>,
+    ) -> Result<()> {
+        require!(
+            self.digital_access
+                .is_valid_presale_proof(&self.buyer.key(), allocation, proof),
+            DappError::InvalidPresaleProof
+        );
+
+        let (Some(presale_claim), Some(bump)) = (self.presale_claim.as_mut(), bump) else {
+            return err!(DappError::MissingPresaleClaim);
+        };
+
+        require!(
+            presale_claim.claimed < allocation.unwrap_or(1),
+            DappError::PresaleAllocationExceeded
+        );
+
+        presale_claim.digital_access = self.digital_access.key();
+        presale_claim.buyer = self.buyer.key();
+        presale_claim.claimed += 1;
+        presale_claim.bump = bump;
+
+        Ok(())
+    }
+
+    pub fn record_purchase(&mut self, bump: Option<u8>) -> Result<()> {
+        let event_limit = self.event.max_per_wallet;
+        let tier_limit = self.digital_access.max_per_wallet;
+
+        if event_limit.is_none() && tier_limit.is_none() {
+            return Ok(());
+        }
+
+        let (Some(buyer_record), Some(bump)) = (self.buyer_record.as_mut(), bump) else {
+            return err!(DappError::MissingBuyerRecord);
+        };
+
+        buyer_record.event = self.event.key();
+        buyer_record.buyer = self.buyer.key();
+        buyer_record.bump = bump;
+        buyer_record.purchased += 1;
+
+        if let Some(limit) = event_limit {
+            require!(
+                buyer_record.purchased <= limit,
+                DappError::PurchaseLimitExceeded
+            );
+        }
+
+        if let Some(limit) = tier_limit {
+            let purchased = buyer_record.add_tier_purchase(self.digital_access.id)?;
+            require!(purchased <= limit, DappError::PurchaseLimitExceeded);
+        }
+
+        Ok(())
+    }
+
+    pub fn apply_discount(&mut self, code: Option<String>, price: u64) -> Result<u64> {
+        let discount = match (self.discount.as_mut(), code) {
+            (None, None) => return Ok(price),
+            (Some(discount), Some(code)) if discount.is_valid_code(&code) => discount,
+            _ => return err!(DappError::InvalidDiscountCode),
+        };
+
+        require!(
+            discount.is_applicable(Clock::get()?.unix_timestamp, self.digital_access.id),
+            DappError::DiscountNotApplicable
+        );
 
-    // transfer SOL to event creator
-    system_program::transfer(
-        CpiContext::new(
-            acc.system_program.to_account_info(),
-            system_program::Transfer {
-                from: acc.buyer.to_account_info(),
-                // to: acc.treasury.to_account_info()
-                to: acc.event_creator.to_account_info()
+        discount.uses += 1;
+
+        discount.apply(price)
+    }
+
+    pub fn collect_payment(&self, price: u64) -> Result<()> {
+        // escrowed proceeds stay whole in the vault, the fee is taken on settlement
+        let fee = if self.event.escrow {
+            0
+        } else {
+            self.config.protocol_fee(price)?
+        };
+
+        if self.digital_access.payment_mint.is_none() {
+            // transfer protocol fee to treasury
+            if fee > 0 {
+                transfer_lamports(
+                    &self.system_program,
+                    &self.buyer.to_account_info(),
+                    &self.treasury.to_account_info(),
+                    fee,
+                    &[],
+                )?;
             }
-        ),
-        acc.digital_access.price
-    )?;
 
+            // transfer SOL to event creator or vault
+            let destination = if self.event.escrow {
+                self.vault.to_account_info()
+            } else {
+                self.event_creator.to_account_info()
+            };
+
+            return transfer_lamports(
+                &self.system_program,
+                &self.buyer.to_account_info(),
+                &destination,
+                price - fee,
+                &[],
+            );
+        }
+
+        let (Some(payment_mint), Some(buyer_payment_token_account), Some(payment_token_program)) = (
+            &self.payment_mint,
+            &self.buyer_payment_token_account,
+            &self.payment_token_program,
+        ) else {
+            return err!(DappError::MissingPaymentAccounts);
+        };
+
+        // transfer protocol fee to treasury token account
+        if fee > 0 {
+            let treasury_payment_token_account = self
+                .treasury_payment_token_account
+                .as_ref()
+                .ok_or(DappError::MissingPaymentAccounts)?;
+
+            transfer_tokens(
+                payment_token_program,
+                buyer_payment_token_account,
+                treasury_payment_token_account,
+                &self.buyer.to_account_info(),
+                payment_mint,
+                fee,
+                &[],
+            )?;
+        }
+
+        // transfer tokens to event creator or vault
+        let destination = if self.event.escrow {
+            &self.vault_payment_token_account
+        } else {
+            &self.creator_payment_token_account
+        };
+
+        transfer_tokens(
+            payment_token_program,
+            buyer_payment_token_account,
+            destination
+                .as_ref()
+                .ok_or(DappError::MissingPaymentAccounts)?,
+            &self.buyer.to_account_info(),
+            payment_mint,
+            price - fee,
+            &[],
+        )
+    }
+}
+
+pub fn process_buy_token(
+    ctx: Context<BuyToken>,
+    event_id: u64,
+    _digital_access_id: u8,
+    proof: Option<Vec<[u8; 32]>>,
+    allocation: Option<u64>,
+    discount_code: Option<String>,
+    max_price: u64,
+) -> Result<()> {
+    ctx.accounts.validate()?;
+
+    if ctx
+        .accounts
+        .digital_access
+        .is_in_presale(Clock::get()?.unix_timestamp)
+    {
+        ctx.accounts.claim_presale(
+            &proof.unwrap_or_default(),
+            allocation,
+            ctx.bumps.presale_claim,
+        )?;
+    }
+
+    ctx.accounts.record_purchase(ctx.bumps.buyer_record)?;
+
+    let base_price = ctx
+        .accounts
+        .digital_access
+        .current_price(Clock::get()?.unix_timestamp)?;
+    let price = ctx.accounts.apply_discount(discount_code, base_price)?;
+    require!(price <= max_price, DappError::PriceAboveMax);
+
+    ctx.accounts.collect_payment(price)?;
+
+    // prices only go down during the auction, so the last one paid is the lowest
+    if ctx.accounts.digital_access.is_auction() {
+        let digital_access = &mut ctx.accounts.digital_access;
+        digital_access.clearing_price = base_price;
+
+        // tickets bought for free have no rebate to hold back on settlement
+        if price > 0 {
+            digital_access.unclaimed_bids = digital_access
+                .unclaimed_bids
+                .checked_add(base_price)
+                .ok_or(DappError::ArithmeticOverflow)?;
+            digital_access.unclaimed_bidders += 1;
+        }
+    }
+
+    let acc = &ctx.accounts;
     let signer_seeds: &[&[&[u8]]] = &[&[
         SEED_COLLECTION_MINT,
         &event_id.to_le_bytes(),
@@ -187,7 +493,8 @@ pub fn process_buy_token(ctx: Context<BuyToken>, event_id: u64, _digital_access_
         format!("{} #{}", acc.digital_access.name, acc.event.current_nft_count),
         acc.digital_access.symbol.clone(),
         acc.digital_access.uri.clone(),
-        0,
+        acc.event.seller_fee_basis_points,
+        &acc.event.creators,
         Some(Collection{ key: acc.collection_mint.key(), verified: false }),
         true,
         None
@@ -216,7 +523,31 @@ pub fn process_buy_token(ctx: Context<BuyToken>, event_id: u64, _digital_access_
         signer_seeds
     )?;
 
+    if !acc.digital_access.transferable {
+        freeze_token_account(
+            &acc.token_metadata_program,
+            &acc.buyer.to_account_info(),
+            &acc.buyer_token_account,
+            &acc.token_mint,
+            &acc.edition_account,
+            &acc.collection_mint,
+            &acc.token_program,
+            signer_seeds,
+        )?;
+    }
+
+    ctx.accounts.ticket.set_inner(Ticket {
+        event: ctx.accounts.event.key(),
+        digital_access: ctx.accounts.digital_access.key(),
+        token_mint: ctx.accounts.token_mint.key(),
+        price_paid: price,
+        base_price,
+        payment_mint: ctx.accounts.digital_access.payment_mint,
+        bump: ctx.bumps.ticket,
+    });
+
     ctx.accounts.event.current_nft_count += 1;
+    ctx.accounts.event.open_tickets += 1;
     ctx.accounts.digital_access.current_minted += 1;
 
     Ok(())
@@ -0,0 +1,259 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{
+        self, spl_token_2022::instruction::AuthorityType, Mint, TokenAccount, TokenInterface,
+    },
+};
+
+use crate::{
+    Config, DappError, DigitalAccess, Event, Ticket, ANCHOR_DISCRIMINATOR, SEED_COLLECTION_MINT,
+    SEED_CONFIG, SEED_DIGITAL_ACCESS, SEED_EVENT, SEED_TICKET, SEED_TOKEN_MINT, SEED_TREASURY,
+};
+
+use super::{
+    initialize_token_2022_member, initialize_token_2022_metadata, mint_to, transfer_lamports,
+};
+
+#[derive(Accounts)]
+#[instruction(event_id: u64, digital_access_id: u8)]
+pub struct BuyToken2022<'info> {
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_CONFIG],
+        bump = config.bump,
+        constraint = !config.paused @ DappError::ProgramPaused,
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        mut,
+        seeds = [SEED_EVENT, event_id.to_le_bytes().as_ref()],
+        bump = event.bump,
+        constraint = event.collection_mint_account == collection_mint.key(),
+        constraint = event.token_2022 @ DappError::NotToken2022Event,
+        constraint = !event.paused @ DappError::EventPaused,
+    )]
+    pub event: Box<Account<'info, Event>>,
+
+    #[account(mut, address = event.creator)]
+    pub event_creator: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            SEED_DIGITAL_ACCESS,
+            event.key().as_ref(),
+            digital_access_id.to_le_bytes().as_ref()
+        ],
+        bump = digital_access.bump,
+        has_one = event,
+        constraint = digital_access.max_supply > digital_access.current_minted @ DappError::MaxSupplyReached,
+    )]
+    pub digital_access: Box<Account<'info, DigitalAccess>>,
+
+    #[account(
+        mut,
+        seeds = [SEED_TREASURY],
+        bump = config.treasury_bump
+    )]
+    pub treasury: SystemAccount<'info>,
+
+    // the ticket mint holds its own metadata and is a member of the collection group
+    #[account(
+        init,
+        payer = buyer,
+        mint::decimals = 0,
+        mint::authority = collection_mint,
+        mint::freeze_authority = collection_mint,
+        mint::token_program = token_program,
+        extensions::metadata_pointer::authority = collection_mint,
+        extensions::metadata_pointer::metadata_address = token_mint,
+        extensions::group_member_pointer::authority = collection_mint,
+        extensions::group_member_pointer::member_address = token_mint,
+        seeds = [
+            SEED_TOKEN_MINT,
+            collection_mint.key().as_ref(),
+            event.current_nft_count.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        init_if_needed,
+        payer = buyer,
+        associated_token::mint = token_mint,
+        associated_token::authority = buyer,
+        associated_token::token_program = token_program,
+    )]
+    pub buyer_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init,
+        payer = buyer,
+        space = Ticket::INIT_SPACE + ANCHOR_DISCRIMINATOR,
+        seeds = [SEED_TICKET, token_mint.key().as_ref()],
+        bump
+    )]
+    pub ticket: Box<Account<'info, Ticket>>,
+
+    #[account(
+        mut,
+        seeds = [SEED_COLLECTION_MINT, event_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub collection_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(address = anchor_spl::token_2022::ID)]
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> BuyToken2022<'info> {
+    pub fn validate(&self) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+
+        require!(self.event.is_on_sale(now), DappError::EventNotActive);
+        require!(
+            self.digital_access.is_on_sale(now) && !self.digital_access.is_in_presale(now),
+            DappError::SaleNotActive
+        );
+        // token-2022 tickets are paid in SOL and carry no per-wallet limits
+        require!(
+            self.digital_access.payment_mint.is_none(),
+            DappError::UnsupportedPaymentMint
+        );
+        require!(
+            self.digital_access.max_per_wallet.is_none(),
+            DappError::Token2022TierNotSupported
+        );
+
+        Ok(())
+    }
+
+    pub fn collect_payment(&self, price: u64) -> Result<()> {
+        let fee = self.config.protocol_fee(price)?;
+
+        // transfer protocol fee to treasury
+        if fee > 0 {
+            transfer_lamports(
+                &self.system_program,
+                &self.buyer.to_account_info(),
+                &self.treasury.to_account_info(),
+                fee,
+                &[],
+            )?;
+        }
+
+        // transfer SOL to event creator
+        transfer_lamports(
+            &self.system_program,
+            &self.buyer.to_account_info(),
+            &self.event_creator.to_account_info(),
+            price - fee,
+            &[],
+        )
+    }
+}
+
+pub fn process_buy_token_2022(
+    ctx: Context<BuyToken2022>,
+    event_id: u64,
+    _digital_access_id: u8,
+    max_price: u64,
+) -> Result<()> {
+    ctx.accounts.validate()?;
+
+    let price = ctx
+        .accounts
+        .digital_access
+        .current_price(Clock::get()?.unix_timestamp)?;
+    require!(price <= max_price, DappError::PriceAboveMax);
+    ctx.accounts.collect_payment(price)?;
+
+    let acc = &ctx.accounts;
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        SEED_COLLECTION_MINT,
+        &event_id.to_le_bytes(),
+        &[ctx.bumps.collection_mint],
+    ]];
+
+    initialize_token_2022_metadata(
+        &acc.token_program,
+        &acc.system_program,
+        &acc.buyer,
+        &acc.token_mint,
+        &acc.collection_mint,
+        signer_seeds,
+        format!(
+            "{} #{}",
+            acc.digital_access.name, acc.event.current_nft_count
+        ),
+        acc.digital_access.symbol.clone(),
+        acc.digital_access.uri.clone(),
+    )?;
+
+    initialize_token_2022_member(
+        &acc.token_program,
+        &acc.system_program,
+        &acc.buyer,
+        &acc.token_mint,
+        &acc.collection_mint,
+        signer_seeds,
+    )?;
+
+    mint_to(
+        &acc.token_program,
+        &acc.token_mint,
+        &acc.buyer_token_account,
+        &acc.collection_mint,
+        signer_seeds,
+    )?;
+
+    // without a master edition, dropping the mint authority keeps the supply at one
+    token_interface::set_authority(
+        CpiContext::new_with_signer(
+            acc.token_program.to_account_info(),
+            token_interface::SetAuthority {
+                current_authority: acc.collection_mint.to_account_info(),
+                account_or_mint: acc.token_mint.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        AuthorityType::MintTokens,
+        None,
+    )?;
+
+    // the collection mint keeps the freeze authority, so soulbound tickets are frozen directly
+    if !acc.digital_access.transferable {
+        token_interface::freeze_account(CpiContext::new_with_signer(
+            acc.token_program.to_account_info(),
+            token_interface::FreezeAccount {
+                account: acc.buyer_token_account.to_account_info(),
+                mint: acc.token_mint.to_account_info(),
+                authority: acc.collection_mint.to_account_info(),
+            },
+            signer_seeds,
+        ))?;
+    }
+
+    ctx.accounts.ticket.set_inner(Ticket {
+        event: ctx.accounts.event.key(),
+        digital_access: ctx.accounts.digital_access.key(),
+        token_mint: ctx.accounts.token_mint.key(),
+        price_paid: price,
+        base_price: price,
+        payment_mint: None,
+        bump: ctx.bumps.ticket,
+    });
+
+    ctx.accounts.event.current_nft_count += 1;
+    ctx.accounts.event.open_tickets += 1;
+    ctx.accounts.digital_access.current_minted += 1;
+
+    Ok(())
+}
@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::{Config, DappError, Event, EventStatus, SEED_CONFIG, SEED_EVENT};
+
+#[derive(Accounts)]
+#[instruction(event_id: u64)]
+pub struct CancelEvent<'info> {
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_CONFIG],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [SEED_EVENT, event_id.to_le_bytes().as_ref()],
+        bump = event.bump,
+        constraint = event.status == EventStatus::Active @ DappError::InvalidEventStatus,
+    )]
+    pub event: Account<'info, Event>,
+}
+
+impl<'info> CancelEvent<'info> {
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.signer.key() == self.event.creator || self.signer.key() == self.config.authority,
+            DappError::InvalidAuthority
+        );
+
+        Ok(())
+    }
+}
+
+pub fn process_cancel_event(ctx: Context<CancelEvent>, _event_id: u64) -> Result<()> {
+    ctx.accounts.validate()?;
+
+    ctx.accounts.event.status = EventStatus::Cancelled;
+
+    Ok(())
+}
@@ -0,0 +1,74 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+use crate::{Listing, SEED_LISTING};
+
+use super::{close_token_account, transfer_tokens};
+
+#[derive(Accounts)]
+pub struct CancelListing<'info> {
+    #[account(mut)]
+    pub seller: Signer<'info>,
+
+    #[account(
+        mut,
+        close = seller,
+        seeds = [SEED_LISTING, token_mint.key().as_ref()],
+        bump = listing.bump,
+        has_one = seller,
+        has_one = token_mint,
+    )]
+    pub listing: Box<Account<'info, Listing>>,
+
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = listing,
+        associated_token::token_program = token_program,
+    )]
+    pub escrow_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = seller,
+        associated_token::mint = token_mint,
+        associated_token::authority = seller,
+        associated_token::token_program = token_program,
+    )]
+    pub seller_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn process_cancel_listing(ctx: Context<CancelListing>) -> Result<()> {
+    let acc = &ctx.accounts;
+    let token_mint_key = acc.token_mint.key();
+    let signer_seeds: &[&[&[u8]]] =
+        &[&[SEED_LISTING, token_mint_key.as_ref(), &[acc.listing.bump]]];
+
+    // return the ticket to the seller
+    transfer_tokens(
+        &acc.token_program,
+        &acc.escrow_token_account,
+        &acc.seller_token_account,
+        &acc.listing.to_account_info(),
+        &acc.token_mint,
+        1,
+        signer_seeds,
+    )?;
+
+    close_token_account(
+        &acc.token_program,
+        &acc.escrow_token_account,
+        &acc.seller.to_account_info(),
+        &acc.listing.to_account_info(),
+        signer_seeds,
+    )
+}
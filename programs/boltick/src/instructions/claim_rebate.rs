@@ -0,0 +1,191 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+use crate::{
+    DappError, DigitalAccess, Event, EventStatus, Ticket, SEED_DIGITAL_ACCESS, SEED_EVENT,
+    SEED_TICKET, SEED_TOKEN_MINT, SEED_VAULT,
+};
+
+use super::{transfer_lamports, transfer_tokens};
+
+#[derive(Accounts)]
+#[instruction(event_id: u64, digital_access_id: u8, token_id: u64)]
+pub struct ClaimRebate<'info> {
+    #[account(mut)]
+    pub holder: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_EVENT, event_id.to_le_bytes().as_ref()],
+        bump = event.bump,
+        constraint = matches!(event.status, EventStatus::Active | EventStatus::Settled)
+            @ DappError::InvalidEventStatus,
+        constraint = event.escrow @ DappError::EventNotEscrowed,
+    )]
+    pub event: Box<Account<'info, Event>>,
+
+    #[account(
+        mut,
+        seeds = [
+            SEED_DIGITAL_ACCESS,
+            event.key().as_ref(),
+            digital_access_id.to_le_bytes().as_ref()
+        ],
+        bump = digital_access.bump,
+        has_one = event,
+    )]
+    pub digital_access: Box<Account<'info, DigitalAccess>>,
+
+    #[account(
+        mut,
+        seeds = [SEED_VAULT, event.key().as_ref()],
+        bump = event.vault_bump
+    )]
+    pub vault: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED_TICKET, token_mint.key().as_ref()],
+        bump = ticket.bump,
+        has_one = event,
+        has_one = digital_access,
+    )]
+    pub ticket: Box<Account<'info, Ticket>>,
+
+    #[account(
+        seeds = [
+            SEED_TOKEN_MINT,
+            event.collection_mint_account.as_ref(),
+            token_id.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        associated_token::mint = token_mint,
+        associated_token::authority = holder,
+        associated_token::token_program = token_program,
+        constraint = holder_token_account.amount == 1 @ DappError::TicketNotOwned,
+    )]
+    pub holder_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    // payment accounts, only required when the ticket was paid with a payment mint
+    #[account(
+        constraint = ticket.payment_mint == Some(payment_mint.key()) @ DappError::PaymentMintMismatch,
+        mint::token_program = payment_token_program,
+    )]
+    pub payment_mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+
+    #[account(
+        init_if_needed,
+        payer = holder,
+        associated_token::mint = payment_mint,
+        associated_token::authority = holder,
+        associated_token::token_program = payment_token_program,
+    )]
+    pub holder_payment_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(
+        mut,
+        associated_token::mint = payment_mint,
+        associated_token::authority = vault,
+        associated_token::token_program = payment_token_program,
+    )]
+    pub vault_payment_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    pub payment_token_program: Option<Interface<'info, TokenInterface>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ClaimRebate<'info> {
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.digital_access.is_auction()
+                && self
+                    .digital_access
+                    .is_auction_over(&self.event, Clock::get()?.unix_timestamp),
+            DappError::AuctionNotOver
+        );
+        require!(
+            self.ticket.rebate(self.digital_access.clearing_price) > 0,
+            DappError::NoRebateAvailable
+        );
+
+        Ok(())
+    }
+
+    pub fn pay_rebate(&self, amount: u64) -> Result<()> {
+        let event_key = self.event.key();
+        let signer_seeds: &[&[&[u8]]] =
+            &[&[SEED_VAULT, event_key.as_ref(), &[self.event.vault_bump]]];
+
+        if self.ticket.payment_mint.is_none() {
+            return transfer_lamports(
+                &self.system_program,
+                &self.vault.to_account_info(),
+                &self.holder.to_account_info(),
+                amount,
+                signer_seeds,
+            );
+        }
+
+        let (
+            Some(payment_mint),
+            Some(holder_payment_token_account),
+            Some(vault_payment_token_account),
+            Some(payment_token_program),
+        ) = (
+            &self.payment_mint,
+            &self.holder_payment_token_account,
+            &self.vault_payment_token_account,
+            &self.payment_token_program,
+        )
+        else {
+            return err!(DappError::MissingPaymentAccounts);
+        };
+
+        transfer_tokens(
+            payment_token_program,
+            vault_payment_token_account,
+            holder_payment_token_account,
+            &self.vault.to_account_info(),
+            payment_mint,
+            amount,
+            signer_seeds,
+        )
+    }
+}
+
+pub fn process_claim_rebate(
+    ctx: Context<ClaimRebate>,
+    _event_id: u64,
+    _digital_access_id: u8,
+    _token_id: u64,
+) -> Result<()> {
+    ctx.accounts.validate()?;
+
+    let amount = ctx
+        .accounts
+        .ticket
+        .rebate(ctx.accounts.digital_access.clearing_price);
+
+    ctx.accounts.pay_rebate(amount)?;
+
+    // the rebate is no longer held back in the vault once it is paid
+    let digital_access = &mut ctx.accounts.digital_access;
+    digital_access.remove_unclaimed_bid(ctx.accounts.ticket.base_price);
+
+    // the ticket now counts as bought at the clearing price less its discount, also for refunds
+    let clearing_price = digital_access.clearing_price;
+    let ticket = &mut ctx.accounts.ticket;
+    ticket.price_paid -= amount;
+    ticket.base_price = clearing_price;
+
+    Ok(())
+}
@@ -0,0 +1,262 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    metadata::Metadata,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+use crate::{
+    DappError, DigitalAccess, Event, EventStatus, Ticket, SEED_COLLECTION_MINT, SEED_EVENT,
+    SEED_TICKET, SEED_TOKEN_MINT, SEED_VAULT,
+};
+
+use super::{burn_nft, thaw_token_account, transfer_lamports, transfer_tokens};
+
+#[derive(Accounts)]
+#[instruction(event_id: u64, token_id: u64)]
+pub struct ClaimRefund<'info> {
+    #[account(mut)]
+    pub holder: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED_EVENT, event_id.to_le_bytes().as_ref()],
+        bump = event.bump,
+        constraint = event.escrow @ DappError::EventNotEscrowed,
+    )]
+    pub event: Box<Account<'info, Event>>,
+
+    #[account(mut, has_one = event)]
+    pub digital_access: Box<Account<'info, DigitalAccess>>,
+
+    #[account(
+        mut,
+        seeds = [SEED_VAULT, event.key().as_ref()],
+        bump = event.vault_bump
+    )]
+    pub vault: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        close = holder,
+        seeds = [SEED_TICKET, token_mint.key().as_ref()],
+        bump = ticket.bump,
+        has_one = event,
+        has_one = digital_access,
+    )]
+    pub ticket: Box<Account<'info, Ticket>>,
+
+    #[account(
+        mut,
+        seeds = [
+            SEED_TOKEN_MINT,
+            event.collection_mint_account.as_ref(),
+            token_id.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = holder,
+        associated_token::token_program = token_program,
+        constraint = holder_token_account.amount == 1 @ DappError::TicketNotOwned,
+    )]
+    pub holder_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        seeds = [SEED_COLLECTION_MINT, event_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub collection_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// CHECK: Validate address by deriving pda
+    #[account(
+        mut,
+        seeds = [
+            b"metadata",
+            token_metadata_program.key().as_ref(),
+            token_mint.key().as_ref()
+        ],
+        bump,
+        seeds::program = token_metadata_program.key(),
+    )]
+    pub metadata_account: UncheckedAccount<'info>,
+
+    /// CHECK: Validate address by deriving pda
+    #[account(
+        mut,
+        seeds = [
+            b"metadata",
+            token_metadata_program.key().as_ref(),
+            token_mint.key().as_ref(),
+            b"edition"
+        ],
+        bump,
+        seeds::program = token_metadata_program.key(),
+    )]
+    pub edition_account: UncheckedAccount<'info>,
+
+    /// CHECK: Validate address by deriving pda
+    #[account(
+        mut,
+        seeds = [
+            b"metadata",
+            token_metadata_program.key().as_ref(),
+            event.collection_mint_account.as_ref()
+        ],
+        bump,
+        seeds::program = token_metadata_program.key(),
+    )]
+    pub collection_metadata_account: UncheckedAccount<'info>,
+
+    // payment accounts, only required when the ticket was paid with a payment mint
+    #[account(
+        constraint = ticket.payment_mint == Some(payment_mint.key()) @ DappError::PaymentMintMismatch,
+        mint::token_program = payment_token_program,
+    )]
+    pub payment_mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+
+    #[account(
+        init_if_needed,
+        payer = holder,
+        associated_token::mint = payment_mint,
+        associated_token::authority = holder,
+        associated_token::token_program = payment_token_program,
+    )]
+    pub holder_payment_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(
+        mut,
+        associated_token::mint = payment_mint,
+        associated_token::authority = vault,
+        associated_token::token_program = payment_token_program,
+    )]
+    pub vault_payment_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    pub payment_token_program: Option<Interface<'info, TokenInterface>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub token_metadata_program: Program<'info, Metadata>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ClaimRefund<'info> {
+    pub fn validate(&self) -> Result<()> {
+        if self.event.status == EventStatus::Cancelled {
+            return Ok(());
+        }
+
+        // while buyers wait for a sold out tier, holders can return their ticket for its seat
+        let now = Clock::get()?.unix_timestamp;
+        require!(
+            self.event.is_on_sale(now)
+                && self.digital_access.is_on_sale(now)
+                && self.digital_access.waitlist_len > 0,
+            DappError::RefundNotAvailable
+        );
+
+        Ok(())
+    }
+
+    pub fn refund_payment(&self) -> Result<()> {
+        let amount = self.ticket.price_paid;
+        if amount == 0 {
+            return Ok(());
+        }
+
+        let event_key = self.event.key();
+        let signer_seeds: &[&[&[u8]]] =
+            &[&[SEED_VAULT, event_key.as_ref(), &[self.event.vault_bump]]];
+
+        if self.ticket.payment_mint.is_none() {
+            return transfer_lamports(
+                &self.system_program,
+                &self.vault.to_account_info(),
+                &self.holder.to_account_info(),
+                amount,
+                signer_seeds,
+            );
+        }
+
+        let (
+            Some(payment_mint),
+            Some(holder_payment_token_account),
+            Some(vault_payment_token_account),
+            Some(payment_token_program),
+        ) = (
+            &self.payment_mint,
+            &self.holder_payment_token_account,
+            &self.vault_payment_token_account,
+            &self.payment_token_program,
+        )
+        else {
+            return err!(DappError::MissingPaymentAccounts);
+        };
+
+        transfer_tokens(
+            payment_token_program,
+            vault_payment_token_account,
+            holder_payment_token_account,
+            &self.vault.to_account_info(),
+            payment_mint,
+            amount,
+            signer_seeds,
+        )
+    }
+}
+
+pub fn process_claim_refund(
+    ctx: Context<ClaimRefund>,
+    event_id: u64,
+    _token_id: u64,
+) -> Result<()> {
+    ctx.accounts.validate()?;
+
+    let acc = &ctx.accounts;
+
+    // soulbound tickets have to be thawed before they can be burned
+    if acc.holder_token_account.is_frozen() {
+        let signer_seeds: &[&[&[u8]]] = &[&[
+            SEED_COLLECTION_MINT,
+            &event_id.to_le_bytes(),
+            &[ctx.bumps.collection_mint],
+        ]];
+
+        thaw_token_account(
+            &acc.token_metadata_program,
+            &acc.holder_token_account,
+            &acc.token_mint,
+            &acc.edition_account,
+            &acc.collection_mint,
+            &acc.token_program,
+            signer_seeds,
+        )?;
+    }
+
+    burn_nft(
+        &acc.token_metadata_program,
+        &acc.metadata_account,
+        &acc.holder,
+        &acc.token_mint,
+        &acc.holder_token_account,
+        &acc.edition_account,
+        &acc.token_program,
+        &acc.collection_metadata_account,
+    )?;
+
+    acc.refund_payment()?;
+
+    ctx.accounts.event.open_tickets -= 1;
+    ctx.accounts
+        .digital_access
+        .forfeit_rebate(&ctx.accounts.ticket);
+    ctx.accounts
+        .digital_access
+        .release_seat(&ctx.accounts.event, Clock::get()?.unix_timestamp);
+
+    Ok(())
+}
@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    DappError, DigitalAccess, Event, EventRole, Role, SEED_DIGITAL_ACCESS, SEED_EVENT,
+    SEED_EVENT_ROLE,
+};
+
+#[derive(Accounts)]
+#[instruction(event_id: u64, digital_access_id: u8)]
+pub struct CloseDigitalAccess<'info> {
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_EVENT, event_id.to_le_bytes().as_ref()],
+        bump = event.bump,
+    )]
+    pub event: Account<'info, Event>,
+
+    // only required when the signer is not the event creator
+    #[account(
+        seeds = [SEED_EVENT_ROLE, event.key().as_ref(), signer.key().as_ref()],
+        bump = event_role.bump,
+    )]
+    pub event_role: Option<Account<'info, EventRole>>,
+
+    // the account is kept since minted tickets still reference it
+    #[account(
+        mut,
+        seeds = [
+            SEED_DIGITAL_ACCESS,
+            event.key().as_ref(),
+            digital_access_id.to_le_bytes().as_ref()
+        ],
+        bump = digital_access.bump,
+        has_one = event,
+        constraint = !digital_access.closed @ DappError::DigitalAccessClosed,
+    )]
+    pub digital_access: Account<'info, DigitalAccess>,
+}
+
+impl<'info> CloseDigitalAccess<'info> {
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.event.has_role(
+                &self.signer.key(),
+                self.event_role.as_deref(),
+                Role::Manager
+            ),
+            DappError::MissingEventRole
+        );
+
+        Ok(())
+    }
+}
+
+pub fn process_close_digital_access(
+    ctx: Context<CloseDigitalAccess>,
+    _event_id: u64,
+    _digital_access_id: u8,
+) -> Result<()> {
+    ctx.accounts.validate()?;
+
+    ctx.accounts.digital_access.closed = true;
+
+    Ok(())
+}
@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    DappError, Discount, Event, EventRole, Role, SEED_DISCOUNT, SEED_EVENT, SEED_EVENT_ROLE,
+};
+
+#[derive(Accounts)]
+#[instruction(event_id: u64, code_hash: [u8; 32])]
+pub struct CloseDiscount<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_EVENT, event_id.to_le_bytes().as_ref()],
+        bump = event.bump,
+    )]
+    pub event: Account<'info, Event>,
+
+    // only required when the signer is not the event creator
+    #[account(
+        seeds = [SEED_EVENT_ROLE, event.key().as_ref(), signer.key().as_ref()],
+        bump = event_role.bump,
+    )]
+    pub event_role: Option<Account<'info, EventRole>>,
+
+    #[account(
+        mut,
+        close = signer,
+        seeds = [SEED_DISCOUNT, event.key().as_ref(), code_hash.as_ref()],
+        bump = discount.bump,
+        has_one = event,
+    )]
+    pub discount: Account<'info, Discount>,
+}
+
+impl<'info> CloseDiscount<'info> {
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.event.has_role(
+                &self.signer.key(),
+                self.event_role.as_deref(),
+                Role::Manager
+            ),
+            DappError::MissingEventRole
+        );
+
+        Ok(())
+    }
+}
+
+pub fn process_close_discount(
+    ctx: Context<CloseDiscount>,
+    _event_id: u64,
+    _code_hash: [u8; 32],
+) -> Result<()> {
+    ctx.accounts.validate()
+}
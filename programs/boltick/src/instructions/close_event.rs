@@ -0,0 +1,198 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::get_associated_token_address_with_program_id,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+use crate::{DappError, DigitalAccess, Event, SEED_EVENT, SEED_VAULT};
+
+use super::{close_token_account, transfer_lamports, transfer_tokens};
+
+#[derive(Accounts)]
+#[instruction(event_id: u64)]
+pub struct CloseEvent<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    #[account(
+        mut,
+        close = creator,
+        seeds = [SEED_EVENT, event_id.to_le_bytes().as_ref()],
+        bump = event.bump,
+        has_one = creator @ DappError::InvalidCreator,
+    )]
+    pub event: Account<'info, Event>,
+
+    #[account(
+        mut,
+        seeds = [SEED_VAULT, event.key().as_ref()],
+        bump = event.vault_bump
+    )]
+    pub vault: SystemAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+    // remaining accounts: every digital access of the event, ordered by id, followed by the
+    // payment mint, vault token account, creator token account and token program of each
+    // payment mint of those digital accesses, in the order they first appear
+}
+
+impl<'info> CloseEvent<'info> {
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.event.is_closable(Clock::get()?.unix_timestamp),
+            DappError::EventNotClosable
+        );
+        // tickets need the event to be burned, refunded or resold
+        require!(self.event.open_tickets == 0, DappError::TicketsOutstanding);
+
+        Ok(())
+    }
+
+    // returns the payment mints of the digital accesses, without duplicates
+    pub fn close_digital_accesses(
+        &self,
+        accounts: &'info [AccountInfo<'info>],
+    ) -> Result<Vec<Pubkey>> {
+        require!(
+            accounts.len() == self.event.current_digital_access_count as usize,
+            DappError::InvalidDigitalAccessAccounts
+        );
+
+        let mut payment_mints = Vec::new();
+
+        for (id, account) in accounts.iter().enumerate() {
+            let digital_access = Account::<DigitalAccess>::try_from(account)?;
+
+            require!(
+                digital_access.event == self.event.key() && digital_access.id as usize == id,
+                DappError::InvalidDigitalAccessAccounts
+            );
+            // waitlist entries still hold their escrow and need the digital access to leave
+            require!(
+                digital_access.waitlist_len == 0,
+                DappError::WaitlistNotEmpty
+            );
+            // rebates held back on settlement belong to the auction buyers
+            require!(
+                digital_access.outstanding_rebates()? == 0,
+                DappError::RebatesOutstanding
+            );
+
+            if let Some(payment_mint) = digital_access.payment_mint {
+                if !payment_mints.contains(&payment_mint) {
+                    payment_mints.push(payment_mint);
+                }
+            }
+
+            digital_access.close(self.creator.to_account_info())?;
+        }
+
+        Ok(payment_mints)
+    }
+
+    pub fn close_vault_token_accounts(
+        &self,
+        payment_mints: &[Pubkey],
+        accounts: &'info [AccountInfo<'info>],
+    ) -> Result<()> {
+        require!(
+            accounts.len() == payment_mints.len() * 4,
+            DappError::InvalidVaultTokenAccounts
+        );
+
+        let event_key = self.event.key();
+        let signer_seeds: &[&[&[u8]]] =
+            &[&[SEED_VAULT, event_key.as_ref(), &[self.event.vault_bump]]];
+
+        for (payment_mint_key, accounts) in payment_mints.iter().zip(accounts.chunks(4)) {
+            let payment_mint = InterfaceAccount::<Mint>::try_from(&accounts[0])?;
+            let payment_token_program = Interface::<TokenInterface>::try_from(&accounts[3])?;
+
+            require!(
+                payment_mint.key() == *payment_mint_key
+                    && accounts[1].key()
+                        == get_associated_token_address_with_program_id(
+                            &self.vault.key(),
+                            payment_mint_key,
+                            &payment_token_program.key(),
+                        ),
+                DappError::InvalidVaultTokenAccounts
+            );
+
+            // the vault token account only exists once an escrowed purchase created it
+            if accounts[1].data_is_empty() {
+                continue;
+            }
+
+            let vault_payment_token_account =
+                InterfaceAccount::<TokenAccount>::try_from(&accounts[1])?;
+
+            if vault_payment_token_account.amount > 0 {
+                let creator_payment_token_account =
+                    InterfaceAccount::<TokenAccount>::try_from(&accounts[2])?;
+
+                require!(
+                    creator_payment_token_account.owner == self.creator.key()
+                        && creator_payment_token_account.mint == *payment_mint_key,
+                    DappError::InvalidVaultTokenAccounts
+                );
+
+                transfer_tokens(
+                    &payment_token_program,
+                    &vault_payment_token_account,
+                    &creator_payment_token_account,
+                    &self.vault.to_account_info(),
+                    &payment_mint,
+                    vault_payment_token_account.amount,
+                    signer_seeds,
+                )?;
+            }
+
+            close_token_account(
+                &payment_token_program,
+                &vault_payment_token_account,
+                &self.creator.to_account_info(),
+                &self.vault.to_account_info(),
+                signer_seeds,
+            )?;
+        }
+
+        Ok(())
+    }
+
+    pub fn sweep_vault(&self) -> Result<()> {
+        let amount = self.vault.lamports();
+        if amount == 0 {
+            return Ok(());
+        }
+
+        let event_key = self.event.key();
+        let signer_seeds: &[&[&[u8]]] =
+            &[&[SEED_VAULT, event_key.as_ref(), &[self.event.vault_bump]]];
+
+        transfer_lamports(
+            &self.system_program,
+            &self.vault.to_account_info(),
+            &self.creator.to_account_info(),
+            amount,
+            signer_seeds,
+        )
+    }
+}
+
+pub fn process_close_event<'info>(
+    ctx: Context<'_, '_, 'info, 'info, CloseEvent<'info>>,
+    _event_id: u64,
+) -> Result<()> {
+    ctx.accounts.validate()?;
+
+    let (digital_accesses, payment_accounts) = ctx.remaining_accounts.split_at(
+        (ctx.accounts.event.current_digital_access_count as usize)
+            .min(ctx.remaining_accounts.len()),
+    );
+
+    let payment_mints = ctx.accounts.close_digital_accesses(digital_accesses)?;
+    ctx.accounts
+        .close_vault_token_accounts(&payment_mints, payment_accounts)?;
+    ctx.accounts.sweep_vault()
+}
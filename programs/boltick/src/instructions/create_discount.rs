@@ -0,0 +1,82 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    DappError, Discount, DiscountKind, Event, EventRole, Role, ANCHOR_DISCRIMINATOR, SEED_DISCOUNT,
+    SEED_EVENT, SEED_EVENT_ROLE,
+};
+
+#[derive(Accounts)]
+#[instruction(event_id: u64, code_hash: [u8; 32])]
+pub struct CreateDiscount<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_EVENT, event_id.to_le_bytes().as_ref()],
+        bump = event.bump,
+    )]
+    pub event: Account<'info, Event>,
+
+    // only required when the signer is not the event creator
+    #[account(
+        seeds = [SEED_EVENT_ROLE, event.key().as_ref(), signer.key().as_ref()],
+        bump = event_role.bump,
+    )]
+    pub event_role: Option<Account<'info, EventRole>>,
+
+    #[account(
+        init,
+        payer = signer,
+        space = Discount::INIT_SPACE + ANCHOR_DISCRIMINATOR,
+        seeds = [SEED_DISCOUNT, event.key().as_ref(), code_hash.as_ref()],
+        bump
+    )]
+    pub discount: Account<'info, Discount>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CreateDiscount<'info> {
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.event.has_role(
+                &self.signer.key(),
+                self.event_role.as_deref(),
+                Role::Manager
+            ),
+            DappError::MissingEventRole
+        );
+
+        Ok(())
+    }
+}
+
+pub fn process_create_discount(
+    ctx: Context<CreateDiscount>,
+    _event_id: u64,
+    code_hash: [u8; 32],
+    kind: DiscountKind,
+    max_uses: u64,
+    expires_at: Option<i64>,
+    digital_access_id: Option<u8>,
+) -> Result<()> {
+    ctx.accounts.validate()?;
+
+    require!(
+        Discount::is_valid_kind(&kind) && max_uses > 0,
+        DappError::InvalidDiscount
+    );
+
+    ctx.accounts.discount.set_inner(Discount {
+        event: ctx.accounts.event.key(),
+        code_hash,
+        kind,
+        max_uses,
+        uses: 0,
+        expires_at,
+        digital_access_id,
+        bump: ctx.bumps.discount,
+    });
+
+    Ok(())
+}
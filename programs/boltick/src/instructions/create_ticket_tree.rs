@@ -0,0 +1,120 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+use crate::{DappError, DigitalAccess, Event, SEED_COLLECTION_MINT, SEED_EVENT};
+
+use super::{create_tree, ACCOUNT_COMPRESSION_PROGRAM_ID, BUBBLEGUM_PROGRAM_ID, NOOP_PROGRAM_ID};
+
+#[derive(Accounts)]
+#[instruction(event_id: u64)]
+pub struct CreateTicketTree<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED_EVENT, event_id.to_le_bytes().as_ref()],
+        bump = event.bump,
+        has_one = creator @ DappError::InvalidCreator,
+        constraint = event.merkle_tree.is_none() @ DappError::CompressedEvent,
+        constraint = !event.token_2022 @ DappError::Token2022Event,
+        constraint = event.current_nft_count == 0 @ DappError::InvalidEventStatus,
+        constraint = !event.escrow @ DappError::CompressedEscrowNotSupported,
+    )]
+    pub event: Account<'info, Event>,
+
+    #[account(
+        seeds = [SEED_COLLECTION_MINT, event_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub collection_mint: InterfaceAccount<'info, Mint>,
+
+    /// CHECK: allocated by the client for the account compression program, initialized by bubblegum
+    #[account(mut)]
+    pub merkle_tree: UncheckedAccount<'info>,
+
+    /// CHECK: Validate address by deriving pda
+    #[account(
+        mut,
+        seeds = [merkle_tree.key().as_ref()],
+        bump,
+        seeds::program = bubblegum_program.key(),
+    )]
+    pub tree_authority: UncheckedAccount<'info>,
+
+    /// CHECK: Validate address
+    #[account(address = BUBBLEGUM_PROGRAM_ID)]
+    pub bubblegum_program: UncheckedAccount<'info>,
+
+    /// CHECK: Validate address
+    #[account(address = ACCOUNT_COMPRESSION_PROGRAM_ID)]
+    pub compression_program: UncheckedAccount<'info>,
+
+    /// CHECK: Validate address
+    #[account(address = NOOP_PROGRAM_ID)]
+    pub log_wrapper: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+    // remaining accounts: every digital access of the event, ordered by id
+}
+
+impl<'info> CreateTicketTree<'info> {
+    // compressed tickets cannot be frozen, so soulbound digital accesses are not supported
+    pub fn validate_digital_accesses(&self, accounts: &'info [AccountInfo<'info>]) -> Result<()> {
+        require!(
+            accounts.len() == self.event.current_digital_access_count as usize,
+            DappError::InvalidDigitalAccessAccounts
+        );
+
+        for (id, account) in accounts.iter().enumerate() {
+            let digital_access = Account::<DigitalAccess>::try_from(account)?;
+
+            require!(
+                digital_access.event == self.event.key() && digital_access.id as usize == id,
+                DappError::InvalidDigitalAccessAccounts
+            );
+            require!(
+                digital_access.transferable,
+                DappError::CompressedTierNotSupported
+            );
+        }
+
+        Ok(())
+    }
+}
+
+pub fn process_create_ticket_tree<'info>(
+    ctx: Context<'_, '_, 'info, 'info, CreateTicketTree<'info>>,
+    event_id: u64,
+    max_depth: u32,
+    max_buffer_size: u32,
+) -> Result<()> {
+    ctx.accounts
+        .validate_digital_accesses(ctx.remaining_accounts)?;
+
+    let acc = &ctx.accounts;
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        SEED_COLLECTION_MINT,
+        &event_id.to_le_bytes(),
+        &[ctx.bumps.collection_mint],
+    ]];
+
+    // the collection mint owns the tree so tickets are minted under the program's authority
+    create_tree(
+        &acc.bubblegum_program,
+        &acc.tree_authority,
+        &acc.merkle_tree,
+        &acc.creator,
+        &acc.collection_mint.to_account_info(),
+        &acc.log_wrapper,
+        &acc.compression_program,
+        &acc.system_program,
+        max_depth,
+        max_buffer_size,
+        signer_seeds,
+    )?;
+
+    ctx.accounts.event.merkle_tree = Some(ctx.accounts.merkle_tree.key());
+
+    Ok(())
+}
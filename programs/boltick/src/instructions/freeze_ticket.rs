@@ -0,0 +1,91 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    metadata::Metadata,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+use crate::{DappError, Event, SEED_COLLECTION_MINT, SEED_EVENT, SEED_TOKEN_MINT};
+
+use super::freeze_token_account;
+
+#[derive(Accounts)]
+#[instruction(event_id: u64, token_id: u64)]
+pub struct FreezeTicket<'info> {
+    pub creator: Signer<'info>,
+
+    // the holder approves the collection mint as delegate before the freeze
+    pub holder: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_EVENT, event_id.to_le_bytes().as_ref()],
+        bump = event.bump,
+        has_one = creator @ DappError::InvalidCreator,
+    )]
+    pub event: Account<'info, Event>,
+
+    #[account(
+        seeds = [
+            SEED_TOKEN_MINT,
+            collection_mint.key().as_ref(),
+            token_id.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub token_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = holder,
+        associated_token::token_program = token_program,
+    )]
+    pub holder_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        seeds = [SEED_COLLECTION_MINT, event_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub collection_mint: InterfaceAccount<'info, Mint>,
+
+    /// CHECK: Validate address by deriving pda
+    #[account(
+        seeds = [
+            b"metadata",
+            token_metadata_program.key().as_ref(),
+            token_mint.key().as_ref(),
+            b"edition"
+        ],
+        bump,
+        seeds::program = token_metadata_program.key(),
+    )]
+    pub edition_account: UncheckedAccount<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub token_metadata_program: Program<'info, Metadata>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+pub fn process_freeze_ticket(
+    ctx: Context<FreezeTicket>,
+    event_id: u64,
+    _token_id: u64,
+) -> Result<()> {
+    let acc = &ctx.accounts;
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        SEED_COLLECTION_MINT,
+        &event_id.to_le_bytes(),
+        &[ctx.bumps.collection_mint],
+    ]];
+
+    freeze_token_account(
+        &acc.token_metadata_program,
+        &acc.holder.to_account_info(),
+        &acc.holder_token_account,
+        &acc.token_mint,
+        &acc.edition_account,
+        &acc.collection_mint,
+        &acc.token_program,
+        signer_seeds,
+    )
+}
@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+
+use crate::{DappError, Event, EventRole, Role, ANCHOR_DISCRIMINATOR, SEED_EVENT, SEED_EVENT_ROLE};
+
+#[derive(Accounts)]
+#[instruction(event_id: u64, wallet: Pubkey)]
+pub struct GrantRole<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_EVENT, event_id.to_le_bytes().as_ref()],
+        bump = event.bump,
+        has_one = creator @ DappError::InvalidCreator,
+    )]
+    pub event: Account<'info, Event>,
+
+    #[account(
+        init_if_needed,
+        payer = creator,
+        space = EventRole::INIT_SPACE + ANCHOR_DISCRIMINATOR,
+        seeds = [SEED_EVENT_ROLE, event.key().as_ref(), wallet.as_ref()],
+        bump
+    )]
+    pub event_role: Account<'info, EventRole>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn process_grant_role(
+    ctx: Context<GrantRole>,
+    _event_id: u64,
+    wallet: Pubkey,
+    role: Role,
+) -> Result<()> {
+    let event_role = &mut ctx.accounts.event_role;
+
+    event_role.event = ctx.accounts.event.key();
+    event_role.wallet = wallet;
+    event_role.bump = ctx.bumps.event_role;
+    event_role.grant(role);
+
+    Ok(())
+}
@@ -2,6 +2,8 @@ use anchor_lang::prelude::*;
 
 use crate::{Config, ANCHOR_DISCRIMINATOR, SEED_CONFIG, SEED_TREASURY};
 
+use super::transfer_lamports;
+
 #[derive(Accounts)]
 pub struct InitializeConfig<'info> {
     #[account(mut)]
@@ -27,10 +29,22 @@ pub struct InitializeConfig<'info> {
 }
 
 pub fn process_initialize_config(ctx: Context<InitializeConfig>) -> Result<()> {
+    // fund the treasury so it's rent exempt before receiving fees
+    transfer_lamports(
+        &ctx.accounts.system_program,
+        &ctx.accounts.authority.to_account_info(),
+        &ctx.accounts.treasury.to_account_info(),
+        Rent::get()?.minimum_balance(0),
+        &[],
+    )?;
+
     ctx.accounts.config.set_inner(Config {
         authority: ctx.accounts.authority.key(),
+        pending_authority: None,
         treasury: ctx.accounts.treasury.key(),
         event_count: 0,
+        protocol_fee_bps: 0,
+        paused: false,
         treasury_bump: ctx.bumps.treasury,
         bump: ctx.bumps.config,
     });
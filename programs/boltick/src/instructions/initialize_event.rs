@@ -1,9 +1,9 @@
 use anchor_lang::prelude::*;
 use anchor_spl::{metadata::{self, mpl_token_metadata::types::CollectionDetails, Metadata}, token_interface::{Mint, TokenAccount, TokenInterface}};
 
-use crate::{Config, Event, ANCHOR_DISCRIMINATOR, SEED_COLLECTION_MINT, SEED_COLLECTION_TOKEN_ACCOUNT, SEED_CONFIG, SEED_EVENT};
+use crate::{Config, DappError, Event, EventCreator, EventStatus, ANCHOR_DISCRIMINATOR, SEED_COLLECTION_MINT, SEED_COLLECTION_TOKEN_ACCOUNT, SEED_CONFIG, SEED_EVENT, SEED_VAULT};
 
-use super::{create_master_edition, create_metadata_accounts, mint_to};
+use super::{create_master_edition, create_metadata_accounts, mint_to, transfer_lamports};
 
 #[derive(Accounts)]
 pub struct InitializeEvent<'info> {
@@ -49,6 +49,13 @@ pub struct InitializeEvent<'info> {
     )]
     pub collection_token_account: InterfaceAccount<'info, TokenAccount>,
 
+    #[account(
+        mut,
+        seeds = [SEED_VAULT, event.key().as_ref()],
+        bump
+    )]
+    pub vault: SystemAccount<'info>,
+
     /// CHECK: Validate address by deriving pda
     #[account(
         mut,
@@ -82,13 +89,31 @@ pub struct InitializeEvent<'info> {
     pub rent: Sysvar<'info, Rent>,
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn process_initialize_event(
     ctx: Context<InitializeEvent>,
     name: String,
     symbol: String,
     uri: String,
-    event_description: String
+    event_description: String,
+    start_time: i64,
+    end_time: i64,
+    sales_start: i64,
+    sales_end: i64,
+    escrow: bool,
+    seller_fee_basis_points: u16,
+    creators: Vec<EventCreator>,
+    max_per_wallet: Option<u64>,
 ) -> Result<()> {
+    require!(
+        Event::is_valid_schedule(start_time, end_time, sales_start, sales_end),
+        DappError::InvalidSchedule
+    );
+    require!(
+        Event::is_valid_royalty(seller_fee_basis_points, &creators),
+        DappError::InvalidCreators
+    );
+
     let acc = &ctx.accounts;
     let event_count: [u8; 8] = acc.config.event_count.to_le_bytes();
 
@@ -120,9 +145,10 @@ pub fn process_initialize_event(
         name.clone(),
         symbol,
         uri,
-        0,
+        seller_fee_basis_points,
+        &creators,
         None,
-        false,
+        true, // mutable so update_event can edit the collection
         Some(CollectionDetails::V1 { size: 0 })
     )?;
     
@@ -141,22 +167,49 @@ pub fn process_initialize_event(
     )?;
 
     // verify collection metadata 
-    metadata::sign_metadata(CpiContext::new_with_signer(
-        acc.token_metadata_program.to_account_info(),
-        metadata::SignMetadata {
-            creator: acc.collection_mint.to_account_info(),
-            metadata: acc.metadata_account.to_account_info()
-        },
-        signer_seeds
-    ))?;
-
+    if creators.is_empty() || creators.iter().any(|creator| creator.address == acc.collection_mint.key()) {
+        metadata::sign_metadata(CpiContext::new_with_signer(
+            acc.token_metadata_program.to_account_info(),
+            metadata::SignMetadata {
+                creator: acc.collection_mint.to_account_info(),
+                metadata: acc.metadata_account.to_account_info()
+            },
+            signer_seeds
+        ))?;
+    }
+
+
+    // fund the vault so it's rent exempt before receiving proceeds
+    if escrow {
+        transfer_lamports(
+            &acc.system_program,
+            &acc.creator.to_account_info(),
+            &acc.vault.to_account_info(),
+            Rent::get()?.minimum_balance(0),
+            &[],
+        )?;
+    }
 
     ctx.accounts.event.set_inner(Event {
         creator: acc.creator.key(),
         collection_mint_account: acc.collection_mint.key(),
         current_nft_count: 0,
+        open_tickets: 0,
         current_digital_access_count: 0,
+        escrow,
+        status: EventStatus::Active,
+        paused: false,
+        vault_bump: ctx.bumps.vault,
         date: Clock::get()?.unix_timestamp,
+        start_time,
+        end_time,
+        sales_start,
+        sales_end,
+        seller_fee_basis_points,
+        max_per_wallet,
+        merkle_tree: None,
+        token_2022: false,
+        creators,
         name,
         description: event_description,
         bump: ctx.bumps.event,
@@ -0,0 +1,140 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenInterface};
+
+use crate::{
+    Config, DappError, Event, EventCreator, EventStatus, ANCHOR_DISCRIMINATOR,
+    SEED_COLLECTION_MINT, SEED_CONFIG, SEED_EVENT, SEED_VAULT,
+};
+
+use super::{initialize_token_2022_group, initialize_token_2022_metadata};
+
+#[derive(Accounts)]
+pub struct InitializeToken2022Event<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    #[account(
+        init,
+        payer = creator,
+        space = Event::INIT_SPACE + ANCHOR_DISCRIMINATOR,
+        seeds = [SEED_EVENT, config.event_count.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub event: Box<Account<'info, Event>>,
+
+    #[account(
+        mut,
+        seeds = [SEED_CONFIG],
+        bump = config.bump
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    // the collection mint holds its own metadata and acts as the group of the tickets
+    #[account(
+        init,
+        payer = creator,
+        mint::decimals = 0,
+        mint::authority = collection_mint,
+        mint::freeze_authority = collection_mint,
+        mint::token_program = token_program,
+        extensions::metadata_pointer::authority = collection_mint,
+        extensions::metadata_pointer::metadata_address = collection_mint,
+        extensions::group_pointer::authority = collection_mint,
+        extensions::group_pointer::group_address = collection_mint,
+        seeds = [SEED_COLLECTION_MINT, config.event_count.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub collection_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        seeds = [SEED_VAULT, event.key().as_ref()],
+        bump
+    )]
+    pub vault: SystemAccount<'info>,
+
+    #[account(address = anchor_spl::token_2022::ID)]
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn process_initialize_token_2022_event(
+    ctx: Context<InitializeToken2022Event>,
+    name: String,
+    symbol: String,
+    uri: String,
+    event_description: String,
+    start_time: i64,
+    end_time: i64,
+    sales_start: i64,
+    sales_end: i64,
+    seller_fee_basis_points: u16,
+    creators: Vec<EventCreator>,
+) -> Result<()> {
+    require!(
+        Event::is_valid_schedule(start_time, end_time, sales_start, sales_end),
+        DappError::InvalidSchedule
+    );
+    require!(
+        Event::is_valid_royalty(seller_fee_basis_points, &creators),
+        DappError::InvalidCreators
+    );
+
+    let acc = &ctx.accounts;
+    let event_count: [u8; 8] = acc.config.event_count.to_le_bytes();
+
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        SEED_COLLECTION_MINT,
+        &event_count,
+        &[ctx.bumps.collection_mint],
+    ]];
+
+    initialize_token_2022_metadata(
+        &acc.token_program,
+        &acc.system_program,
+        &acc.creator,
+        &acc.collection_mint,
+        &acc.collection_mint,
+        signer_seeds,
+        name.clone(),
+        symbol,
+        uri,
+    )?;
+
+    initialize_token_2022_group(
+        &acc.token_program,
+        &acc.system_program,
+        &acc.creator,
+        &acc.collection_mint,
+        signer_seeds,
+    )?;
+
+    ctx.accounts.event.set_inner(Event {
+        creator: acc.creator.key(),
+        collection_mint_account: acc.collection_mint.key(),
+        current_nft_count: 0,
+        open_tickets: 0,
+        current_digital_access_count: 0,
+        escrow: false,
+        status: EventStatus::Active,
+        paused: false,
+        vault_bump: ctx.bumps.vault,
+        date: Clock::get()?.unix_timestamp,
+        start_time,
+        end_time,
+        sales_start,
+        sales_end,
+        seller_fee_basis_points,
+        max_per_wallet: None,
+        merkle_tree: None,
+        token_2022: true,
+        creators,
+        name,
+        description: event_description,
+        bump: ctx.bumps.event,
+    });
+
+    ctx.accounts.config.event_count += 1;
+
+    Ok(())
+}
@@ -0,0 +1,107 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    DappError, DigitalAccess, Event, WaitlistEntry, ANCHOR_DISCRIMINATOR, SEED_DIGITAL_ACCESS,
+    SEED_EVENT, SEED_WAITLIST_ENTRY,
+};
+
+use super::transfer_lamports;
+
+#[derive(Accounts)]
+#[instruction(event_id: u64, digital_access_id: u8)]
+pub struct JoinWaitlist<'info> {
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_EVENT, event_id.to_le_bytes().as_ref()],
+        bump = event.bump,
+        constraint = !event.paused @ DappError::EventPaused,
+    )]
+    pub event: Box<Account<'info, Event>>,
+
+    #[account(
+        mut,
+        seeds = [
+            SEED_DIGITAL_ACCESS,
+            event.key().as_ref(),
+            digital_access_id.to_le_bytes().as_ref()
+        ],
+        bump = digital_access.bump,
+        has_one = event,
+    )]
+    pub digital_access: Box<Account<'info, DigitalAccess>>,
+
+    #[account(
+        init,
+        payer = buyer,
+        space = WaitlistEntry::INIT_SPACE + ANCHOR_DISCRIMINATOR,
+        seeds = [
+            SEED_WAITLIST_ENTRY,
+            digital_access.key().as_ref(),
+            digital_access.waitlist_tail.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub waitlist_entry: Account<'info, WaitlistEntry>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> JoinWaitlist<'info> {
+    pub fn validate(&self) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+
+        require!(self.event.is_on_sale(now), DappError::EventNotActive);
+        require!(
+            self.digital_access.is_on_sale(now),
+            DappError::SaleNotActive
+        );
+        require!(self.digital_access.is_sold_out(), DappError::NotSoldOut);
+        require!(
+            self.event.merkle_tree.is_none()
+                && !self.event.token_2022
+                && self.event.max_per_wallet.is_none()
+                && self.digital_access.supports_waitlist(),
+            DappError::WaitlistNotSupported
+        );
+
+        Ok(())
+    }
+}
+
+pub fn process_join_waitlist(
+    ctx: Context<JoinWaitlist>,
+    _event_id: u64,
+    _digital_access_id: u8,
+) -> Result<()> {
+    ctx.accounts.validate()?;
+
+    let amount = ctx
+        .accounts
+        .digital_access
+        .current_price(Clock::get()?.unix_timestamp)?;
+
+    transfer_lamports(
+        &ctx.accounts.system_program,
+        &ctx.accounts.buyer.to_account_info(),
+        &ctx.accounts.waitlist_entry.to_account_info(),
+        amount,
+        &[],
+    )?;
+
+    let digital_access = &mut ctx.accounts.digital_access;
+
+    ctx.accounts.waitlist_entry.set_inner(WaitlistEntry {
+        digital_access: digital_access.key(),
+        buyer: ctx.accounts.buyer.key(),
+        position: digital_access.waitlist_tail,
+        amount,
+        bump: ctx.bumps.waitlist_entry,
+    });
+
+    digital_access.waitlist_tail += 1;
+    digital_access.waitlist_len += 1;
+
+    Ok(())
+}
@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    DigitalAccess, Event, WaitlistEntry, SEED_DIGITAL_ACCESS, SEED_EVENT, SEED_WAITLIST_ENTRY,
+};
+
+#[derive(Accounts)]
+#[instruction(event_id: u64, digital_access_id: u8, position: u64)]
+pub struct LeaveWaitlist<'info> {
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_EVENT, event_id.to_le_bytes().as_ref()],
+        bump = event.bump,
+    )]
+    pub event: Box<Account<'info, Event>>,
+
+    #[account(
+        mut,
+        seeds = [
+            SEED_DIGITAL_ACCESS,
+            event.key().as_ref(),
+            digital_access_id.to_le_bytes().as_ref()
+        ],
+        bump = digital_access.bump,
+        has_one = event,
+    )]
+    pub digital_access: Box<Account<'info, DigitalAccess>>,
+
+    // closing the entry returns the escrowed price together with the rent
+    #[account(
+        mut,
+        close = buyer,
+        seeds = [
+            SEED_WAITLIST_ENTRY,
+            digital_access.key().as_ref(),
+            position.to_le_bytes().as_ref()
+        ],
+        bump = waitlist_entry.bump,
+        has_one = buyer,
+    )]
+    pub waitlist_entry: Account<'info, WaitlistEntry>,
+}
+
+pub fn process_leave_waitlist(
+    ctx: Context<LeaveWaitlist>,
+    _event_id: u64,
+    _digital_access_id: u8,
+    _position: u64,
+) -> Result<()> {
+    ctx.accounts.digital_access.remove_waitlist_entry();
+
+    Ok(())
+}
@@ -0,0 +1,130 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+use crate::{
+    DappError, DigitalAccess, Event, EventStatus, Listing, Ticket, ANCHOR_DISCRIMINATOR,
+    SEED_EVENT, SEED_LISTING, SEED_REDEMPTION, SEED_TICKET, SEED_TOKEN_MINT,
+};
+
+use super::transfer_tokens;
+
+#[derive(Accounts)]
+#[instruction(event_id: u64, token_id: u64)]
+pub struct ListTicket<'info> {
+    #[account(mut)]
+    pub seller: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_EVENT, event_id.to_le_bytes().as_ref()],
+        bump = event.bump,
+        constraint = event.status == EventStatus::Active @ DappError::InvalidEventStatus,
+    )]
+    pub event: Box<Account<'info, Event>>,
+
+    #[account(
+        seeds = [SEED_TICKET, token_mint.key().as_ref()],
+        bump = ticket.bump,
+        has_one = event,
+        has_one = digital_access,
+    )]
+    pub ticket: Box<Account<'info, Ticket>>,
+
+    pub digital_access: Box<Account<'info, DigitalAccess>>,
+
+    #[account(
+        seeds = [
+            SEED_TOKEN_MINT,
+            event.collection_mint_account.as_ref(),
+            token_id.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = seller,
+        associated_token::token_program = token_program,
+        constraint = seller_token_account.amount == 1 @ DappError::TicketNotOwned,
+    )]
+    pub seller_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: Validate address by deriving pda, it only exists once the ticket is redeemed
+    #[account(
+        seeds = [SEED_REDEMPTION, token_mint.key().as_ref()],
+        bump,
+        constraint = redemption.data_is_empty() @ DappError::TicketAlreadyRedeemed,
+    )]
+    pub redemption: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = seller,
+        space = Listing::INIT_SPACE + ANCHOR_DISCRIMINATOR,
+        seeds = [SEED_LISTING, token_mint.key().as_ref()],
+        bump
+    )]
+    pub listing: Box<Account<'info, Listing>>,
+
+    #[account(
+        init,
+        payer = seller,
+        associated_token::mint = token_mint,
+        associated_token::authority = listing,
+        associated_token::token_program = token_program,
+    )]
+    pub escrow_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ListTicket<'info> {
+    pub fn validate(&self, price: u64) -> Result<()> {
+        require!(
+            self.digital_access.payment_mint.is_none(),
+            DappError::UnsupportedPaymentMint
+        );
+        require!(
+            self.digital_access.is_valid_resale_price(price)?,
+            DappError::ResalePriceTooHigh
+        );
+
+        Ok(())
+    }
+}
+
+pub fn process_list_ticket(
+    ctx: Context<ListTicket>,
+    _event_id: u64,
+    _token_id: u64,
+    price: u64,
+) -> Result<()> {
+    ctx.accounts.validate(price)?;
+
+    let acc = &ctx.accounts;
+    transfer_tokens(
+        &acc.token_program,
+        &acc.seller_token_account,
+        &acc.escrow_token_account,
+        &acc.seller.to_account_info(),
+        &acc.token_mint,
+        1,
+        &[],
+    )?;
+
+    ctx.accounts.listing.set_inner(Listing {
+        seller: ctx.accounts.seller.key(),
+        event: ctx.accounts.event.key(),
+        token_mint: ctx.accounts.token_mint.key(),
+        price,
+        bump: ctx.bumps.listing,
+    });
+
+    Ok(())
+}
@@ -6,12 +6,14 @@ use anchor_spl::{
 };
 
 use crate::{
-    Config, DappError, DigitalAccess, Event, SEED_COLLECTION_MINT, SEED_CONFIG,
-    SEED_DIGITAL_ACCESS, SEED_EVENT, SEED_TOKEN_MINT,
+    Config, DappError, DigitalAccess, Event, EventRole, Role, Ticket, ANCHOR_DISCRIMINATOR,
+    SEED_COLLECTION_MINT, SEED_CONFIG, SEED_DIGITAL_ACCESS, SEED_EVENT, SEED_EVENT_ROLE,
+    SEED_TICKET, SEED_TOKEN_MINT,
 };
 
 use super::{
-    create_master_edition, create_metadata_accounts, mint_to, set_and_verify_sized_collection_item,
+    create_master_edition, create_metadata_accounts, freeze_token_account, mint_to,
+    set_and_verify_sized_collection_item,
 };
 
 #[derive(Accounts)]
@@ -26,7 +28,7 @@ pub struct MintToken<'info> {
     #[account(
         seeds = [SEED_CONFIG],
         bump = config.bump,
-        has_one = authority @ DappError::InvalidAuthority
+        constraint = !config.paused @ DappError::ProgramPaused,
     )]
     pub config: Account<'info, Config>,
 
@@ -34,10 +36,18 @@ pub struct MintToken<'info> {
         mut,
         seeds = [SEED_EVENT, event_id.to_le_bytes().as_ref()],
         bump = event.bump,
-        constraint = event.collection_mint_account == collection_mint.key()
+        constraint = event.collection_mint_account == collection_mint.key(),
+        constraint = !event.paused @ DappError::EventPaused,
     )]
     pub event: Account<'info, Event>,
 
+    // only required when the authority is not the config authority or the event creator
+    #[account(
+        seeds = [SEED_EVENT_ROLE, event.key().as_ref(), authority.key().as_ref()],
+        bump = event_role.bump,
+    )]
+    pub event_role: Option<Account<'info, EventRole>>,
+
     #[account(
         mut,
         seeds = [
@@ -72,6 +82,15 @@ pub struct MintToken<'info> {
     )]
     pub destination_token_account: InterfaceAccount<'info, TokenAccount>,
 
+    #[account(
+        init,
+        payer = authority,
+        space = Ticket::INIT_SPACE + ANCHOR_DISCRIMINATOR,
+        seeds = [SEED_TICKET, token_mint.key().as_ref()],
+        bump
+    )]
+    pub ticket: Box<Account<'info, Ticket>>,
+
     #[account(
         mut,
         seeds = [SEED_COLLECTION_MINT, event_id.to_le_bytes().as_ref()],
@@ -140,11 +159,49 @@ pub struct MintToken<'info> {
     pub rent: Sysvar<'info, Rent>,
 }
 
+impl<'info> MintToken<'info> {
+    pub fn validate(&self) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+
+        // the config authority mints for every event, the creator and minters for their own
+        require!(
+            self.authority.key() == self.config.authority
+                || self.event.has_role(
+                    &self.authority.key(),
+                    self.event_role.as_deref(),
+                    Role::Minter
+                ),
+            DappError::InvalidAuthority
+        );
+
+        require!(self.event.is_on_sale(now), DappError::EventNotActive);
+        require!(self.event.merkle_tree.is_none(), DappError::CompressedEvent);
+        require!(!self.event.token_2022, DappError::Token2022Event);
+        require!(
+            self.digital_access.is_on_sale(now),
+            DappError::SaleNotActive
+        );
+        // freed seats belong to the waitlist, they are handed out by process_waitlist
+        require!(
+            self.digital_access.waitlist_len == 0,
+            DappError::WaitlistNotEmpty
+        );
+        require!(
+            self.digital_access.transferable || self.destination.is_signer,
+            DappError::DestinationMustSign
+        );
+
+        Ok(())
+    }
+}
+
 pub fn process_mint_token(
     ctx: Context<MintToken>,
     event_id: u64,
     _digital_access_id: u8,
 ) -> Result<()> {
+    ctx.accounts.validate()?;
+
     let acc = &ctx.accounts;
 
     let signer_seeds: &[&[&[u8]]] = &[&[
@@ -178,7 +235,8 @@ pub fn process_mint_token(
         name,
         acc.digital_access.symbol.clone(),
         acc.digital_access.uri.clone(),
-        0,
+        acc.event.seller_fee_basis_points,
+        &acc.event.creators,
         Some(Collection {
             key: acc.collection_mint.key(),
             verified: false, // will be verified then (set_and_verify_sized_collection_item)
@@ -210,7 +268,31 @@ pub fn process_mint_token(
         signer_seeds,
     )?;
 
+    if !acc.digital_access.transferable {
+        freeze_token_account(
+            &acc.token_metadata_program,
+            &acc.destination.to_account_info(),
+            &acc.destination_token_account,
+            &acc.token_mint,
+            &acc.edition_account,
+            &acc.collection_mint,
+            &acc.token_program,
+            signer_seeds,
+        )?;
+    }
+
+    ctx.accounts.ticket.set_inner(Ticket {
+        event: ctx.accounts.event.key(),
+        digital_access: ctx.accounts.digital_access.key(),
+        token_mint: ctx.accounts.token_mint.key(),
+        price_paid: 0,
+        base_price: 0,
+        payment_mint: None,
+        bump: ctx.bumps.ticket,
+    });
+
     ctx.accounts.event.current_nft_count += 1;
+    ctx.accounts.event.open_tickets += 1;
     ctx.accounts.digital_access.current_minted += 1;
 
     Ok(())
@@ -0,0 +1,373 @@
+use anchor_lang::{prelude::*, solana_program::program_pack::Pack};
+use anchor_spl::{
+    associated_token::{self, get_associated_token_address_with_program_id, AssociatedToken},
+    metadata::{mpl_token_metadata::types::Collection, Metadata},
+    token_interface::{self, spl_token_2022, Mint, TokenAccount, TokenInterface},
+};
+
+use crate::{
+    Config, DappError, DigitalAccess, Event, EventRole, Role, Ticket, ANCHOR_DISCRIMINATOR,
+    SEED_COLLECTION_MINT, SEED_CONFIG, SEED_DIGITAL_ACCESS, SEED_EVENT, SEED_EVENT_ROLE,
+    SEED_TICKET, SEED_TOKEN_MINT,
+};
+
+use super::{
+    create_master_edition, create_metadata_accounts, create_pda_account, freeze_token_account,
+    mint_to, set_and_verify_sized_collection_item,
+};
+
+// token_mint, destination_token_account, ticket, metadata_account, edition_account
+const ACCOUNTS_PER_TOKEN: usize = 5;
+
+#[derive(Accounts)]
+#[instruction(event_id: u64, digital_access_id: u8)]
+pub struct MintTokensBatch<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(mut)]
+    pub destination: SystemAccount<'info>,
+
+    #[account(
+        seeds = [SEED_CONFIG],
+        bump = config.bump,
+        constraint = !config.paused @ DappError::ProgramPaused,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [SEED_EVENT, event_id.to_le_bytes().as_ref()],
+        bump = event.bump,
+        constraint = event.collection_mint_account == collection_mint.key(),
+        constraint = !event.paused @ DappError::EventPaused,
+    )]
+    pub event: Account<'info, Event>,
+
+    // only required when the authority is not the config authority or the event creator
+    #[account(
+        seeds = [SEED_EVENT_ROLE, event.key().as_ref(), authority.key().as_ref()],
+        bump = event_role.bump,
+    )]
+    pub event_role: Option<Account<'info, EventRole>>,
+
+    #[account(
+        mut,
+        seeds = [
+            SEED_DIGITAL_ACCESS,
+            event.key().as_ref(),
+            digital_access_id.to_le_bytes().as_ref()
+        ],
+        bump = digital_access.bump,
+        has_one = event,
+    )]
+    pub digital_access: Account<'info, DigitalAccess>,
+
+    #[account(
+        mut,
+        seeds = [SEED_COLLECTION_MINT, event_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub collection_mint: InterfaceAccount<'info, Mint>,
+
+    /// CHECK: Validate address by deriving pda
+    #[account(
+        mut,
+        seeds = [
+            b"metadata",
+            token_metadata_program.key().as_ref(),
+            collection_mint.key().as_ref()
+        ],
+        bump,
+        seeds::program = token_metadata_program.key(),
+    )]
+    pub collection_metadata_account: UncheckedAccount<'info>,
+
+    /// CHECK: Validate address by deriving pda
+    #[account(
+        mut,
+        seeds = [
+            b"metadata",
+            token_metadata_program.key().as_ref(),
+            collection_mint.key().as_ref(),
+            b"edition"
+        ],
+        bump,
+        seeds::program = token_metadata_program.key(),
+    )]
+    pub collection_master_edition: UncheckedAccount<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub token_metadata_program: Program<'info, Metadata>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+impl<'info> MintTokensBatch<'info> {
+    pub fn validate(&self, quantity: u8, remaining_accounts: usize) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+
+        // the config authority mints for every event, the creator and minters for their own
+        require!(
+            self.authority.key() == self.config.authority
+                || self.event.has_role(
+                    &self.authority.key(),
+                    self.event_role.as_deref(),
+                    Role::Minter
+                ),
+            DappError::InvalidAuthority
+        );
+
+        require!(quantity > 0, DappError::ZeroAmount);
+        require!(
+            remaining_accounts == quantity as usize * ACCOUNTS_PER_TOKEN,
+            DappError::InvalidBatchAccounts
+        );
+        require!(self.event.is_on_sale(now), DappError::EventNotActive);
+        require!(self.event.merkle_tree.is_none(), DappError::CompressedEvent);
+        require!(!self.event.token_2022, DappError::Token2022Event);
+        require!(
+            self.digital_access.is_on_sale(now),
+            DappError::SaleNotActive
+        );
+        // freed seats belong to the waitlist, they are handed out by process_waitlist
+        require!(
+            self.digital_access.waitlist_len == 0,
+            DappError::WaitlistNotEmpty
+        );
+        require!(
+            self.digital_access.current_minted + quantity as u64 <= self.digital_access.max_supply,
+            DappError::MaxSupplyReached
+        );
+        require!(
+            self.digital_access.transferable || self.destination.is_signer,
+            DappError::DestinationMustSign
+        );
+
+        Ok(())
+    }
+
+    pub fn mint_one(
+        &mut self,
+        accounts: &'info [AccountInfo<'info>],
+        event_id: u64,
+        collection_mint_bump: u8,
+    ) -> Result<()> {
+        let [token_mint, destination_token_account, ticket, metadata_account, edition_account] =
+            accounts
+        else {
+            return err!(DappError::InvalidBatchAccounts);
+        };
+
+        let collection_mint_key = self.collection_mint.key();
+        let nft_id = self.event.current_nft_count.to_le_bytes();
+        let (token_mint_key, token_mint_bump) = Pubkey::find_program_address(
+            &[
+                SEED_TOKEN_MINT,
+                collection_mint_key.as_ref(),
+                nft_id.as_ref(),
+            ],
+            &crate::ID,
+        );
+        let (ticket_key, ticket_bump) =
+            Pubkey::find_program_address(&[SEED_TICKET, token_mint_key.as_ref()], &crate::ID);
+        let metadata_program_key = self.token_metadata_program.key();
+        let (metadata_key, _) = Pubkey::find_program_address(
+            &[
+                b"metadata",
+                metadata_program_key.as_ref(),
+                token_mint_key.as_ref(),
+            ],
+            &metadata_program_key,
+        );
+        let (edition_key, _) = Pubkey::find_program_address(
+            &[
+                b"metadata",
+                metadata_program_key.as_ref(),
+                token_mint_key.as_ref(),
+                b"edition",
+            ],
+            &metadata_program_key,
+        );
+
+        require!(
+            token_mint.key() == token_mint_key
+                && destination_token_account.key()
+                    == get_associated_token_address_with_program_id(
+                        &self.destination.key(),
+                        &token_mint_key,
+                        &self.token_program.key(),
+                    )
+                && ticket.key() == ticket_key
+                && metadata_account.key() == metadata_key
+                && edition_account.key() == edition_key,
+            DappError::InvalidBatchAccounts
+        );
+
+        let signer_seeds: &[&[&[u8]]] = &[&[
+            SEED_COLLECTION_MINT,
+            &event_id.to_le_bytes(),
+            &[collection_mint_bump],
+        ]];
+
+        // init the token mint
+        create_pda_account(
+            &self.system_program,
+            &self.authority.to_account_info(),
+            token_mint,
+            spl_token_2022::state::Mint::LEN,
+            &self.token_program.key(),
+            &[&[
+                SEED_TOKEN_MINT,
+                collection_mint_key.as_ref(),
+                nft_id.as_ref(),
+                &[token_mint_bump],
+            ]],
+        )?;
+
+        token_interface::initialize_mint2(
+            CpiContext::new(
+                self.token_program.to_account_info(),
+                token_interface::InitializeMint2 {
+                    mint: token_mint.clone(),
+                },
+            ),
+            0,
+            &collection_mint_key,
+            Some(&collection_mint_key),
+        )?;
+
+        associated_token::create_idempotent(CpiContext::new(
+            self.associated_token_program.to_account_info(),
+            associated_token::Create {
+                payer: self.authority.to_account_info(),
+                associated_token: destination_token_account.clone(),
+                authority: self.destination.to_account_info(),
+                mint: token_mint.clone(),
+                system_program: self.system_program.to_account_info(),
+                token_program: self.token_program.to_account_info(),
+            },
+        ))?;
+
+        let token_mint_account = InterfaceAccount::<Mint>::try_from(token_mint)?;
+        let destination_token_account =
+            InterfaceAccount::<TokenAccount>::try_from(destination_token_account)?;
+        let metadata_account = UncheckedAccount::try_from(metadata_account);
+        let edition_account = UncheckedAccount::try_from(edition_account);
+
+        mint_to(
+            &self.token_program,
+            &token_mint_account,
+            &destination_token_account,
+            &self.collection_mint,
+            signer_seeds,
+        )?;
+
+        create_metadata_accounts(
+            &self.token_metadata_program,
+            &metadata_account,
+            &token_mint_account,
+            &self.collection_mint,
+            &self.authority,
+            &self.system_program,
+            &self.rent,
+            signer_seeds,
+            format!(
+                "{} #{}",
+                self.digital_access.name, self.event.current_nft_count
+            ),
+            self.digital_access.symbol.clone(),
+            self.digital_access.uri.clone(),
+            self.event.seller_fee_basis_points,
+            &self.event.creators,
+            Some(Collection {
+                key: collection_mint_key,
+                verified: false,
+            }),
+            true,
+            None,
+        )?;
+
+        create_master_edition(
+            &self.token_metadata_program,
+            &edition_account,
+            &token_mint_account,
+            &self.collection_mint,
+            &self.authority,
+            &metadata_account,
+            &self.token_program,
+            &self.system_program,
+            &self.rent,
+            signer_seeds,
+        )?;
+
+        set_and_verify_sized_collection_item(
+            &self.token_metadata_program,
+            &metadata_account,
+            &self.authority,
+            &self.collection_mint,
+            &self.collection_metadata_account,
+            &self.collection_master_edition,
+            signer_seeds,
+        )?;
+
+        if !self.digital_access.transferable {
+            freeze_token_account(
+                &self.token_metadata_program,
+                &self.destination.to_account_info(),
+                &destination_token_account,
+                &token_mint_account,
+                &edition_account,
+                &self.collection_mint,
+                &self.token_program,
+                signer_seeds,
+            )?;
+        }
+
+        // init the ticket
+        let space = Ticket::INIT_SPACE + ANCHOR_DISCRIMINATOR;
+        create_pda_account(
+            &self.system_program,
+            &self.authority.to_account_info(),
+            ticket,
+            space,
+            &crate::ID,
+            &[&[SEED_TICKET, token_mint_key.as_ref(), &[ticket_bump]]],
+        )?;
+
+        Ticket {
+            event: self.event.key(),
+            digital_access: self.digital_access.key(),
+            token_mint: token_mint_key,
+            price_paid: 0,
+            base_price: 0,
+            payment_mint: None,
+            bump: ticket_bump,
+        }
+        .try_serialize(&mut &mut ticket.try_borrow_mut_data()?[..])?;
+
+        self.event.current_nft_count += 1;
+        self.event.open_tickets += 1;
+        self.digital_access.current_minted += 1;
+
+        Ok(())
+    }
+}
+
+pub fn process_mint_tokens_batch<'info>(
+    ctx: Context<'_, '_, 'info, 'info, MintTokensBatch<'info>>,
+    event_id: u64,
+    _digital_access_id: u8,
+    quantity: u8,
+) -> Result<()> {
+    ctx.accounts
+        .validate(quantity, ctx.remaining_accounts.len())?;
+
+    for accounts in ctx.remaining_accounts.chunks(ACCOUNTS_PER_TOKEN) {
+        ctx.accounts
+            .mint_one(accounts, event_id, ctx.bumps.collection_mint)?;
+    }
+
+    Ok(())
+}
@@ -1,15 +1,93 @@
+pub mod accept_authority;
 pub mod add_digital_access;
+pub mod bubblegum;
+pub mod burn_ticket;
+pub mod buy_compressed_token;
+pub mod buy_listing;
 pub mod buy_token;
+pub mod buy_token_2022;
+pub mod cancel_event;
+pub mod cancel_listing;
+pub mod claim_rebate;
+pub mod claim_refund;
+pub mod close_digital_access;
+pub mod close_discount;
+pub mod close_event;
+pub mod create_discount;
+pub mod create_ticket_tree;
+pub mod freeze_ticket;
+pub mod grant_role;
 pub mod initialize_config;
 pub mod initialize_event;
+pub mod initialize_token_2022_event;
+pub mod join_waitlist;
+pub mod leave_waitlist;
+pub mod list_ticket;
 pub mod mint_token;
+pub mod mint_tokens_batch;
+pub mod process_waitlist;
+pub mod propose_authority;
+pub mod redeem_compressed_token;
+pub mod redeem_token;
+pub mod revoke_role;
+pub mod set_event_paused;
+pub mod set_paused;
+pub mod set_presale;
+pub mod set_pricing;
+pub mod settle_event;
 pub mod shared;
+pub mod thaw_ticket;
+pub mod update_compressed_token_metadata;
+pub mod update_digital_access;
+pub mod update_event;
+pub mod update_protocol_fee;
 pub mod update_token_metadata;
+pub mod upgrade_ticket;
+pub mod withdraw_treasury;
 
+pub use accept_authority::*;
 pub use add_digital_access::*;
+pub use bubblegum::*;
+pub use burn_ticket::*;
+pub use buy_compressed_token::*;
+pub use buy_listing::*;
 pub use buy_token::*;
+pub use buy_token_2022::*;
+pub use cancel_event::*;
+pub use cancel_listing::*;
+pub use claim_rebate::*;
+pub use claim_refund::*;
+pub use close_digital_access::*;
+pub use close_discount::*;
+pub use close_event::*;
+pub use create_discount::*;
+pub use create_ticket_tree::*;
+pub use freeze_ticket::*;
+pub use grant_role::*;
 pub use initialize_config::*;
 pub use initialize_event::*;
+pub use initialize_token_2022_event::*;
+pub use join_waitlist::*;
+pub use leave_waitlist::*;
+pub use list_ticket::*;
 pub use mint_token::*;
+pub use mint_tokens_batch::*;
+pub use process_waitlist::*;
+pub use propose_authority::*;
+pub use redeem_compressed_token::*;
+pub use redeem_token::*;
+pub use revoke_role::*;
+pub use set_event_paused::*;
+pub use set_paused::*;
+pub use set_presale::*;
+pub use set_pricing::*;
+pub use settle_event::*;
 pub use shared::*;
+pub use thaw_ticket::*;
+pub use update_compressed_token_metadata::*;
+pub use update_digital_access::*;
+pub use update_event::*;
+pub use update_protocol_fee::*;
 pub use update_token_metadata::*;
+pub use upgrade_ticket::*;
+pub use withdraw_treasury::*;
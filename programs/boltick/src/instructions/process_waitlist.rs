@@ -0,0 +1,351 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    metadata::{mpl_token_metadata::types::Collection, Metadata},
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+use crate::{
+    Config, DappError, DigitalAccess, Event, Ticket, WaitlistEntry, ANCHOR_DISCRIMINATOR,
+    SEED_COLLECTION_MINT, SEED_CONFIG, SEED_DIGITAL_ACCESS, SEED_EVENT, SEED_TICKET,
+    SEED_TOKEN_MINT, SEED_TREASURY, SEED_VAULT, SEED_WAITLIST_ENTRY,
+};
+
+use super::{
+    create_master_edition, create_metadata_accounts, mint_to, set_and_verify_sized_collection_item,
+};
+
+#[derive(Accounts)]
+#[instruction(event_id: u64, digital_access_id: u8, position: u64)]
+pub struct ProcessWaitlist<'info> {
+    // anyone can process the waitlist, the payer covers the rent of the new ticket accounts
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_CONFIG],
+        bump = config.bump,
+        constraint = !config.paused @ DappError::ProgramPaused,
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        mut,
+        seeds = [SEED_EVENT, event_id.to_le_bytes().as_ref()],
+        bump = event.bump,
+        constraint = event.collection_mint_account == collection_mint.key(),
+        constraint = !event.paused @ DappError::EventPaused,
+    )]
+    pub event: Box<Account<'info, Event>>,
+
+    #[account(mut, address = event.creator)]
+    pub event_creator: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            SEED_DIGITAL_ACCESS,
+            event.key().as_ref(),
+            digital_access_id.to_le_bytes().as_ref()
+        ],
+        bump = digital_access.bump,
+        has_one = event,
+        constraint = !digital_access.is_sold_out() @ DappError::MaxSupplyReached,
+    )]
+    pub digital_access: Box<Account<'info, DigitalAccess>>,
+
+    #[account(
+        mut,
+        seeds = [SEED_TREASURY],
+        bump = config.treasury_bump
+    )]
+    pub treasury: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED_VAULT, event.key().as_ref()],
+        bump = event.vault_bump
+    )]
+    pub vault: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        close = buyer,
+        seeds = [
+            SEED_WAITLIST_ENTRY,
+            digital_access.key().as_ref(),
+            position.to_le_bytes().as_ref()
+        ],
+        bump = waitlist_entry.bump,
+        has_one = buyer,
+    )]
+    pub waitlist_entry: Box<Account<'info, WaitlistEntry>>,
+
+    #[account(mut)]
+    pub buyer: SystemAccount<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        mint::decimals = 0,
+        mint::authority = collection_mint,
+        mint::freeze_authority = collection_mint,
+        mint::token_program = token_program,
+        seeds = [
+            SEED_TOKEN_MINT,
+            collection_mint.key().as_ref(),
+            event.current_nft_count.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = token_mint,
+        associated_token::authority = buyer,
+        associated_token::token_program = token_program,
+    )]
+    pub buyer_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = Ticket::INIT_SPACE + ANCHOR_DISCRIMINATOR,
+        seeds = [SEED_TICKET, token_mint.key().as_ref()],
+        bump
+    )]
+    pub ticket: Box<Account<'info, Ticket>>,
+
+    #[account(
+        mut,
+        seeds = [SEED_COLLECTION_MINT, event_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub collection_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// CHECK: Validate address by deriving pda
+    #[account(
+        mut,
+        seeds = [
+            b"metadata",
+            token_metadata_program.key().as_ref(),
+            token_mint.key().as_ref()
+        ],
+        bump,
+        seeds::program = token_metadata_program.key(),
+    )]
+    pub metadata_account: UncheckedAccount<'info>,
+
+    /// CHECK: Validate address by deriving pda
+    #[account(
+        mut,
+        seeds = [
+            b"metadata",
+            token_metadata_program.key().as_ref(),
+            token_mint.key().as_ref(),
+            b"edition"
+        ],
+        bump,
+        seeds::program = token_metadata_program.key(),
+    )]
+    pub edition_account: UncheckedAccount<'info>,
+
+    /// CHECK: Validate address by deriving pda
+    #[account(
+        mut,
+        seeds = [
+            b"metadata",
+            token_metadata_program.key().as_ref(),
+            collection_mint.key().as_ref()
+        ],
+        bump,
+        seeds::program = token_metadata_program.key(),
+    )]
+    pub collection_metadata_account: UncheckedAccount<'info>,
+
+    /// CHECK: Validate address by deriving pda
+    #[account(
+        mut,
+        seeds = [
+            b"metadata",
+            token_metadata_program.key().as_ref(),
+            collection_mint.key().as_ref(),
+            b"edition"
+        ],
+        bump,
+        seeds::program = token_metadata_program.key(),
+    )]
+    pub collection_master_edition: UncheckedAccount<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub token_metadata_program: Program<'info, Metadata>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+    // remaining accounts: the left entries from the head of the queue up to `position`
+}
+
+impl<'info> ProcessWaitlist<'info> {
+    pub fn validate(&self) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+
+        require!(self.event.is_on_sale(now), DappError::EventNotActive);
+        require!(
+            self.digital_access.is_on_sale(now),
+            DappError::SaleNotActive
+        );
+
+        Ok(())
+    }
+
+    // entries ahead of `position` are only skipped once their buyers have left
+    pub fn skip_left_entries(&self, position: u64, accounts: &[AccountInfo<'info>]) -> Result<()> {
+        let head = self.digital_access.waitlist_head;
+
+        require!(
+            position >= head && accounts.len() as u64 == position - head,
+            DappError::InvalidWaitlistAccounts
+        );
+
+        let digital_access_key = self.digital_access.key();
+
+        for (account, skipped) in accounts.iter().zip(head..position) {
+            let (entry_key, _) = Pubkey::find_program_address(
+                &[
+                    SEED_WAITLIST_ENTRY,
+                    digital_access_key.as_ref(),
+                    skipped.to_le_bytes().as_ref(),
+                ],
+                &crate::ID,
+            );
+
+            require!(
+                account.key() == entry_key && account.data_is_empty(),
+                DappError::InvalidWaitlistAccounts
+            );
+        }
+
+        Ok(())
+    }
+
+    pub fn collect_payment(&self) -> Result<()> {
+        let amount = self.waitlist_entry.amount;
+
+        // escrowed proceeds stay whole in the vault, the fee is taken on settlement
+        let fee = if self.event.escrow {
+            0
+        } else {
+            self.config.protocol_fee(amount)?
+        };
+
+        let destination = if self.event.escrow {
+            self.vault.to_account_info()
+        } else {
+            self.event_creator.to_account_info()
+        };
+
+        // the entry is owned by the program, so the escrow is moved without a cpi
+        self.waitlist_entry.sub_lamports(amount)?;
+        self.treasury.add_lamports(fee)?;
+        destination.add_lamports(amount - fee)?;
+
+        Ok(())
+    }
+}
+
+pub fn process_process_waitlist<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ProcessWaitlist<'info>>,
+    event_id: u64,
+    _digital_access_id: u8,
+    position: u64,
+) -> Result<()> {
+    ctx.accounts.validate()?;
+    ctx.accounts
+        .skip_left_entries(position, ctx.remaining_accounts)?;
+    ctx.accounts.collect_payment()?;
+
+    let acc = &ctx.accounts;
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        SEED_COLLECTION_MINT,
+        &event_id.to_le_bytes(),
+        &[ctx.bumps.collection_mint],
+    ]];
+
+    mint_to(
+        &acc.token_program,
+        &acc.token_mint,
+        &acc.buyer_token_account,
+        &acc.collection_mint,
+        signer_seeds,
+    )?;
+
+    create_metadata_accounts(
+        &acc.token_metadata_program,
+        &acc.metadata_account,
+        &acc.token_mint,
+        &acc.collection_mint,
+        &acc.payer,
+        &acc.system_program,
+        &acc.rent,
+        signer_seeds,
+        format!(
+            "{} #{}",
+            acc.digital_access.name, acc.event.current_nft_count
+        ),
+        acc.digital_access.symbol.clone(),
+        acc.digital_access.uri.clone(),
+        acc.event.seller_fee_basis_points,
+        &acc.event.creators,
+        Some(Collection {
+            key: acc.collection_mint.key(),
+            verified: false,
+        }),
+        true,
+        None,
+    )?;
+
+    create_master_edition(
+        &acc.token_metadata_program,
+        &acc.edition_account,
+        &acc.token_mint,
+        &acc.collection_mint,
+        &acc.payer,
+        &acc.metadata_account,
+        &acc.token_program,
+        &acc.system_program,
+        &acc.rent,
+        signer_seeds,
+    )?;
+
+    set_and_verify_sized_collection_item(
+        &acc.token_metadata_program,
+        &acc.metadata_account,
+        &acc.payer,
+        &acc.collection_mint,
+        &acc.collection_metadata_account,
+        &acc.collection_master_edition,
+        signer_seeds,
+    )?;
+
+    ctx.accounts.ticket.set_inner(Ticket {
+        event: ctx.accounts.event.key(),
+        digital_access: ctx.accounts.digital_access.key(),
+        token_mint: ctx.accounts.token_mint.key(),
+        price_paid: ctx.accounts.waitlist_entry.amount,
+        base_price: ctx.accounts.waitlist_entry.amount,
+        payment_mint: None,
+        bump: ctx.bumps.ticket,
+    });
+
+    let digital_access = &mut ctx.accounts.digital_access;
+    digital_access.waitlist_head = position + 1;
+    digital_access.remove_waitlist_entry();
+    digital_access.current_minted += 1;
+
+    ctx.accounts.event.current_nft_count += 1;
+    ctx.accounts.event.open_tickets += 1;
+
+    Ok(())
+}
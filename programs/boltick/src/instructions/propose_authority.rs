@@ -0,0 +1,26 @@
+use anchor_lang::prelude::*;
+
+use crate::{Config, DappError, SEED_CONFIG};
+
+#[derive(Accounts)]
+pub struct ProposeAuthority<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED_CONFIG],
+        bump = config.bump,
+        has_one = authority @ DappError::InvalidAuthority,
+    )]
+    pub config: Account<'info, Config>,
+}
+
+pub fn process_propose_authority(
+    ctx: Context<ProposeAuthority>,
+    new_authority: Pubkey,
+) -> Result<()> {
+    // the current authority keeps control until the new one accepts
+    ctx.accounts.config.pending_authority = Some(new_authority);
+
+    Ok(())
+}
@@ -0,0 +1,118 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    DappError, Event, EventRole, Redemption, Role, ANCHOR_DISCRIMINATOR, SEED_EVENT,
+    SEED_EVENT_ROLE, SEED_REDEMPTION,
+};
+
+use super::{
+    compressed_asset_id, compressed_leaf_hash, verify_leaf, ACCOUNT_COMPRESSION_PROGRAM_ID,
+};
+
+#[derive(Accounts)]
+#[instruction(event_id: u64, root: [u8; 32], data_hash: [u8; 32], creator_hash: [u8; 32], nonce: u64)]
+pub struct RedeemCompressedToken<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_EVENT, event_id.to_le_bytes().as_ref()],
+        bump = event.bump,
+        constraint = event.merkle_tree == Some(merkle_tree.key()) @ DappError::NotCompressedEvent,
+    )]
+    pub event: Account<'info, Event>,
+
+    // only required when the signer is not the event creator
+    #[account(
+        seeds = [SEED_EVENT_ROLE, event.key().as_ref(), signer.key().as_ref()],
+        bump = event_role.bump,
+    )]
+    pub event_role: Option<Account<'info, EventRole>>,
+
+    /// CHECK: part of the verified leaf
+    pub leaf_owner: UncheckedAccount<'info>,
+
+    /// CHECK: part of the verified leaf
+    pub leaf_delegate: UncheckedAccount<'info>,
+
+    /// CHECK: checked against the event and verified by the account compression program
+    pub merkle_tree: UncheckedAccount<'info>,
+
+    // compressed tickets are redeemed by asset id
+    #[account(
+        init_if_needed,
+        payer = signer,
+        space = Redemption::INIT_SPACE + ANCHOR_DISCRIMINATOR,
+        seeds = [
+            SEED_REDEMPTION,
+            compressed_asset_id(&merkle_tree.key(), nonce).as_ref()
+        ],
+        bump
+    )]
+    pub redemption: Account<'info, Redemption>,
+
+    /// CHECK: Validate address
+    #[account(address = ACCOUNT_COMPRESSION_PROGRAM_ID)]
+    pub compression_program: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> RedeemCompressedToken<'info> {
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.event.has_role(
+                &self.signer.key(),
+                self.event_role.as_deref(),
+                Role::Scanner
+            ),
+            DappError::InvalidScanner
+        );
+        require!(!self.redemption.redeemed, DappError::TicketAlreadyRedeemed);
+
+        Ok(())
+    }
+}
+
+pub fn process_redeem_compressed_token<'info>(
+    ctx: Context<'_, '_, '_, 'info, RedeemCompressedToken<'info>>,
+    _event_id: u64,
+    root: [u8; 32],
+    data_hash: [u8; 32],
+    creator_hash: [u8; 32],
+    nonce: u64,
+    index: u32,
+) -> Result<()> {
+    ctx.accounts.validate()?;
+
+    let acc = &ctx.accounts;
+    let asset_id = compressed_asset_id(&acc.merkle_tree.key(), nonce);
+
+    // the proof nodes are passed as remaining accounts
+    verify_leaf(
+        &acc.compression_program,
+        &acc.merkle_tree,
+        ctx.remaining_accounts,
+        root,
+        compressed_leaf_hash(
+            &asset_id,
+            &acc.leaf_owner.key(),
+            &acc.leaf_delegate.key(),
+            nonce,
+            &data_hash,
+            &creator_hash,
+        ),
+        index,
+    )?;
+
+    ctx.accounts.redemption.set_inner(Redemption {
+        event: ctx.accounts.event.key(),
+        token_mint: asset_id,
+        redeemed: true,
+        redeemed_by: ctx.accounts.signer.key(),
+        redeemed_at: Clock::get()?.unix_timestamp,
+        bump: ctx.bumps.redemption,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,84 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+use crate::{
+    DappError, Event, EventRole, Redemption, Role, ANCHOR_DISCRIMINATOR, SEED_EVENT,
+    SEED_EVENT_ROLE, SEED_REDEMPTION, SEED_TOKEN_MINT,
+};
+
+#[derive(Accounts)]
+#[instruction(event_id: u64, token_id: u64)]
+pub struct RedeemToken<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_EVENT, event_id.to_le_bytes().as_ref()],
+        bump = event.bump,
+    )]
+    pub event: Account<'info, Event>,
+
+    // only required when the signer is not the event creator
+    #[account(
+        seeds = [SEED_EVENT_ROLE, event.key().as_ref(), signer.key().as_ref()],
+        bump = event_role.bump,
+    )]
+    pub event_role: Option<Account<'info, EventRole>>,
+
+    #[account(
+        seeds = [
+            SEED_TOKEN_MINT,
+            event.collection_mint_account.as_ref(),
+            token_id.to_le_bytes().as_ref()
+        ],
+        bump,
+        constraint = token_mint.supply == 1,
+    )]
+    pub token_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        init_if_needed,
+        payer = signer,
+        space = Redemption::INIT_SPACE + ANCHOR_DISCRIMINATOR,
+        seeds = [SEED_REDEMPTION, token_mint.key().as_ref()],
+        bump
+    )]
+    pub redemption: Account<'info, Redemption>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> RedeemToken<'info> {
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.event.has_role(
+                &self.signer.key(),
+                self.event_role.as_deref(),
+                Role::Scanner
+            ),
+            DappError::InvalidScanner
+        );
+        require!(!self.redemption.redeemed, DappError::TicketAlreadyRedeemed);
+
+        Ok(())
+    }
+}
+
+pub fn process_redeem_token(
+    ctx: Context<RedeemToken>,
+    _event_id: u64,
+    _token_id: u64,
+) -> Result<()> {
+    ctx.accounts.validate()?;
+
+    ctx.accounts.redemption.set_inner(Redemption {
+        event: ctx.accounts.event.key(),
+        token_mint: ctx.accounts.token_mint.key(),
+        redeemed: true,
+        redeemed_by: ctx.accounts.signer.key(),
+        redeemed_at: Clock::get()?.unix_timestamp,
+        bump: ctx.bumps.redemption,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+use crate::{DappError, Event, EventRole, Role, SEED_EVENT, SEED_EVENT_ROLE};
+
+#[derive(Accounts)]
+#[instruction(event_id: u64, wallet: Pubkey)]
+pub struct RevokeRole<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_EVENT, event_id.to_le_bytes().as_ref()],
+        bump = event.bump,
+        has_one = creator @ DappError::InvalidCreator,
+    )]
+    pub event: Account<'info, Event>,
+
+    #[account(
+        mut,
+        seeds = [SEED_EVENT_ROLE, event.key().as_ref(), wallet.as_ref()],
+        bump = event_role.bump
+    )]
+    pub event_role: Account<'info, EventRole>,
+}
+
+pub fn process_revoke_role(
+    ctx: Context<RevokeRole>,
+    _event_id: u64,
+    _wallet: Pubkey,
+    role: Role,
+) -> Result<()> {
+    ctx.accounts.event_role.revoke(role);
+
+    // the account is closed once the wallet has no roles left
+    if ctx.accounts.event_role.roles == 0 {
+        let creator = ctx.accounts.creator.to_account_info();
+        ctx.accounts.event_role.close(creator)?;
+    }
+
+    Ok(())
+}
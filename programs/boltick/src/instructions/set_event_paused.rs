@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+
+use crate::{DappError, Event, SEED_EVENT};
+
+#[derive(Accounts)]
+#[instruction(event_id: u64)]
+pub struct SetEventPaused<'info> {
+    pub creator: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED_EVENT, event_id.to_le_bytes().as_ref()],
+        bump = event.bump,
+        has_one = creator @ DappError::InvalidCreator,
+    )]
+    pub event: Account<'info, Event>,
+}
+
+pub fn process_set_event_paused(
+    ctx: Context<SetEventPaused>,
+    _event_id: u64,
+    paused: bool,
+) -> Result<()> {
+    ctx.accounts.event.paused = paused;
+
+    Ok(())
+}
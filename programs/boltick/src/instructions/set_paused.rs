@@ -0,0 +1,22 @@
+use anchor_lang::prelude::*;
+
+use crate::{Config, DappError, SEED_CONFIG};
+
+#[derive(Accounts)]
+pub struct SetPaused<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED_CONFIG],
+        bump = config.bump,
+        has_one = authority @ DappError::InvalidAuthority,
+    )]
+    pub config: Account<'info, Config>,
+}
+
+pub fn process_set_paused(ctx: Context<SetPaused>, paused: bool) -> Result<()> {
+    ctx.accounts.config.paused = paused;
+
+    Ok(())
+}
@@ -0,0 +1,76 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    DappError, DigitalAccess, Event, EventRole, Role, SEED_DIGITAL_ACCESS, SEED_EVENT,
+    SEED_EVENT_ROLE,
+};
+
+#[derive(Accounts)]
+#[instruction(event_id: u64, digital_access_id: u8)]
+pub struct SetPresale<'info> {
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_EVENT, event_id.to_le_bytes().as_ref()],
+        bump = event.bump,
+    )]
+    pub event: Account<'info, Event>,
+
+    // only required when the signer is not the event creator
+    #[account(
+        seeds = [SEED_EVENT_ROLE, event.key().as_ref(), signer.key().as_ref()],
+        bump = event_role.bump,
+    )]
+    pub event_role: Option<Account<'info, EventRole>>,
+
+    #[account(
+        mut,
+        seeds = [
+            SEED_DIGITAL_ACCESS,
+            event.key().as_ref(),
+            digital_access_id.to_le_bytes().as_ref()
+        ],
+        bump = digital_access.bump,
+        has_one = event,
+    )]
+    pub digital_access: Account<'info, DigitalAccess>,
+}
+
+impl<'info> SetPresale<'info> {
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.event.has_role(
+                &self.signer.key(),
+                self.event_role.as_deref(),
+                Role::Manager
+            ),
+            DappError::MissingEventRole
+        );
+
+        Ok(())
+    }
+}
+
+pub fn process_set_presale(
+    ctx: Context<SetPresale>,
+    _event_id: u64,
+    _digital_access_id: u8,
+    presale_root: Option<[u8; 32]>,
+    presale_start: Option<i64>,
+    presale_end: Option<i64>,
+) -> Result<()> {
+    ctx.accounts.validate()?;
+
+    require!(
+        DigitalAccess::is_valid_sale_window(presale_start, presale_end),
+        DappError::InvalidSchedule
+    );
+
+    let digital_access = &mut ctx.accounts.digital_access;
+
+    digital_access.presale_root = presale_root;
+    digital_access.presale_start = presale_start;
+    digital_access.presale_end = presale_end;
+
+    Ok(())
+}
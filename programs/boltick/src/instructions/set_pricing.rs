@@ -0,0 +1,84 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    DappError, DigitalAccess, Event, EventRole, PricingMode, Role, SEED_DIGITAL_ACCESS, SEED_EVENT,
+    SEED_EVENT_ROLE,
+};
+
+#[derive(Accounts)]
+#[instruction(event_id: u64, digital_access_id: u8)]
+pub struct SetPricing<'info> {
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_EVENT, event_id.to_le_bytes().as_ref()],
+        bump = event.bump,
+    )]
+    pub event: Account<'info, Event>,
+
+    // only required when the signer is not the event creator
+    #[account(
+        seeds = [SEED_EVENT_ROLE, event.key().as_ref(), signer.key().as_ref()],
+        bump = event_role.bump,
+    )]
+    pub event_role: Option<Account<'info, EventRole>>,
+
+    #[account(
+        mut,
+        seeds = [
+            SEED_DIGITAL_ACCESS,
+            event.key().as_ref(),
+            digital_access_id.to_le_bytes().as_ref()
+        ],
+        bump = digital_access.bump,
+        has_one = event,
+    )]
+    pub digital_access: Account<'info, DigitalAccess>,
+}
+
+impl<'info> SetPricing<'info> {
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.event.has_role(
+                &self.signer.key(),
+                self.event_role.as_deref(),
+                Role::Manager
+            ),
+            DappError::MissingEventRole
+        );
+
+        Ok(())
+    }
+}
+
+pub fn process_set_pricing(
+    ctx: Context<SetPricing>,
+    _event_id: u64,
+    _digital_access_id: u8,
+    pricing: PricingMode,
+) -> Result<()> {
+    ctx.accounts.validate()?;
+
+    require!(
+        DigitalAccess::is_valid_pricing(&pricing),
+        DappError::InvalidPricing
+    );
+    // rebates are paid back from the vault
+    require!(
+        ctx.accounts.event.escrow || !matches!(pricing, PricingMode::DutchAuction { .. }),
+        DappError::AuctionRequiresEscrow
+    );
+
+    // auction buyers are owed rebates that other pricing modes do not account for
+    let digital_access = &ctx.accounts.digital_access;
+    require!(
+        digital_access.current_minted == 0
+            || (!digital_access.is_auction()
+                && !matches!(pricing, PricingMode::DutchAuction { .. })),
+        DappError::AuctionPricingLocked
+    );
+
+    ctx.accounts.digital_access.pricing = pricing;
+
+    Ok(())
+}
@@ -0,0 +1,221 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{
+    Config, DappError, DigitalAccess, Event, EventStatus, SEED_CONFIG, SEED_EVENT, SEED_TREASURY,
+    SEED_VAULT,
+};
+
+use super::{transfer_lamports, transfer_tokens};
+
+#[derive(Accounts)]
+#[instruction(event_id: u64)]
+pub struct SettleEvent<'info> {
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_CONFIG],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [SEED_EVENT, event_id.to_le_bytes().as_ref()],
+        bump = event.bump,
+        constraint = event.escrow @ DappError::EventNotEscrowed,
+        constraint = event.status != EventStatus::Cancelled @ DappError::InvalidEventStatus,
+    )]
+    pub event: Account<'info, Event>,
+
+    #[account(mut, address = event.creator)]
+    pub event_creator: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED_VAULT, event.key().as_ref()],
+        bump = event.vault_bump
+    )]
+    pub vault: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED_TREASURY],
+        bump = config.treasury_bump
+    )]
+    pub treasury: SystemAccount<'info>,
+
+    // payment accounts, only required to settle the proceeds of a payment mint
+    #[account(mint::token_program = payment_token_program)]
+    pub payment_mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+
+    #[account(
+        mut,
+        associated_token::mint = payment_mint,
+        associated_token::authority = vault,
+        associated_token::token_program = payment_token_program,
+    )]
+    pub vault_payment_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(
+        mut,
+        associated_token::mint = payment_mint,
+        associated_token::authority = event_creator,
+        associated_token::token_program = payment_token_program,
+    )]
+    pub creator_payment_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(
+        mut,
+        associated_token::mint = payment_mint,
+        associated_token::authority = treasury,
+        associated_token::token_program = payment_token_program,
+    )]
+    pub treasury_payment_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    pub payment_token_program: Option<Interface<'info, TokenInterface>>,
+
+    pub system_program: Program<'info, System>,
+    // remaining accounts: every digital access of the event, ordered by id
+}
+
+impl<'info> SettleEvent<'info> {
+    pub fn validate(&self) -> Result<()> {
+        // the config authority can release the proceeds early, settling also ends the sales
+        if self.signer.key() == self.config.authority {
+            return Ok(());
+        }
+
+        require!(
+            self.signer.key() == self.event.creator,
+            DappError::InvalidAuthority
+        );
+
+        let now = Clock::get()?.unix_timestamp;
+        require!(
+            self.event.is_settleable(now),
+            DappError::SettlementNotAvailable
+        );
+
+        Ok(())
+    }
+
+    // unclaimed auction rebates in the settled currency stay in the vault for their buyers
+    pub fn outstanding_rebates(&self, accounts: &'info [AccountInfo<'info>]) -> Result<u64> {
+        require!(
+            accounts.len() == self.event.current_digital_access_count as usize,
+            DappError::InvalidDigitalAccessAccounts
+        );
+
+        let payment_mint = self.payment_mint.as_ref().map(|mint| mint.key());
+        let mut outstanding: u64 = 0;
+
+        for (id, account) in accounts.iter().enumerate() {
+            let digital_access = Account::<DigitalAccess>::try_from(account)?;
+
+            require!(
+                digital_access.event == self.event.key() && digital_access.id as usize == id,
+                DappError::InvalidDigitalAccessAccounts
+            );
+
+            if digital_access.payment_mint == payment_mint {
+                outstanding = outstanding
+                    .checked_add(digital_access.outstanding_rebates()?)
+                    .ok_or(DappError::ArithmeticOverflow)?;
+            }
+        }
+
+        Ok(outstanding)
+    }
+
+    pub fn release_proceeds(&self, held_back: u64) -> Result<()> {
+        let event_key = self.event.key();
+        let signer_seeds: &[&[&[u8]]] =
+            &[&[SEED_VAULT, event_key.as_ref(), &[self.event.vault_bump]]];
+
+        let Some(payment_mint) = &self.payment_mint else {
+            // the vault must stay rent exempt
+            let amount = self
+                .vault
+                .lamports()
+                .saturating_sub(Rent::get()?.minimum_balance(0))
+                .saturating_sub(held_back);
+            let fee = self.config.protocol_fee(amount)?;
+
+            if fee > 0 {
+                transfer_lamports(
+                    &self.system_program,
+                    &self.vault.to_account_info(),
+                    &self.treasury.to_account_info(),
+                    fee,
+                    signer_seeds,
+                )?;
+            }
+
+            return transfer_lamports(
+                &self.system_program,
+                &self.vault.to_account_info(),
+                &self.event_creator.to_account_info(),
+                amount - fee,
+                signer_seeds,
+            );
+        };
+
+        let (
+            Some(vault_payment_token_account),
+            Some(creator_payment_token_account),
+            Some(payment_token_program),
+        ) = (
+            &self.vault_payment_token_account,
+            &self.creator_payment_token_account,
+            &self.payment_token_program,
+        )
+        else {
+            return err!(DappError::MissingPaymentAccounts);
+        };
+
+        let amount = vault_payment_token_account.amount.saturating_sub(held_back);
+        let fee = self.config.protocol_fee(amount)?;
+
+        if fee > 0 {
+            let treasury_payment_token_account = self
+                .treasury_payment_token_account
+                .as_ref()
+                .ok_or(DappError::MissingPaymentAccounts)?;
+
+            transfer_tokens(
+                payment_token_program,
+                vault_payment_token_account,
+                treasury_payment_token_account,
+                &self.vault.to_account_info(),
+                payment_mint,
+                fee,
+                signer_seeds,
+            )?;
+        }
+
+        transfer_tokens(
+            payment_token_program,
+            vault_payment_token_account,
+            creator_payment_token_account,
+            &self.vault.to_account_info(),
+            payment_mint,
+            amount - fee,
+            signer_seeds,
+        )
+    }
+}
+
+pub fn process_settle_event<'info>(
+    ctx: Context<'_, '_, 'info, 'info, SettleEvent<'info>>,
+    _event_id: u64,
+) -> Result<()> {
+    ctx.accounts.validate()?;
+
+    let held_back = ctx.accounts.outstanding_rebates(ctx.remaining_accounts)?;
+    ctx.accounts.release_proceeds(held_back)?;
+
+    ctx.accounts.event.status = EventStatus::Settled;
+
+    Ok(())
+}
@@ -1,13 +1,149 @@
-use anchor_lang::prelude::*;
+use anchor_lang::{prelude::*, system_program};
 use anchor_spl::{
     metadata::{
         self,
-        mpl_token_metadata::types::{Collection, CollectionDetails, Creator, DataV2},
+        mpl_token_metadata::{
+            instructions::{BurnNftCpi, BurnNftCpiAccounts},
+            types::{Collection, CollectionDetails, Creator, DataV2},
+        },
         Metadata,
     },
-    token_interface::{self, Mint, TokenAccount, TokenInterface},
+    token_interface::{
+        self, spl_pod::optional_keys::OptionalNonZeroPubkey,
+        spl_token_metadata_interface::state::TokenMetadata, Mint, TokenAccount, TokenInterface,
+    },
 };
 
+use crate::EventCreator;
+
+pub fn transfer_lamports<'info>(
+    system_program: &Program<'info, System>,
+    from: &AccountInfo<'info>,
+    to: &AccountInfo<'info>,
+    amount: u64,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    system_program::transfer(
+        CpiContext::new_with_signer(
+            system_program.to_account_info(),
+            system_program::Transfer {
+                from: from.clone(),
+                to: to.clone(),
+            },
+            signer_seeds,
+        ),
+        amount,
+    )
+}
+
+pub fn transfer_tokens<'info>(
+    token_program: &Interface<'info, TokenInterface>,
+    from: &InterfaceAccount<'info, TokenAccount>,
+    to: &InterfaceAccount<'info, TokenAccount>,
+    authority: &AccountInfo<'info>,
+    mint: &InterfaceAccount<'info, Mint>,
+    amount: u64,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    token_interface::transfer_checked(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            token_interface::TransferChecked {
+                from: from.to_account_info(),
+                to: to.to_account_info(),
+                authority: authority.clone(),
+                mint: mint.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        amount,
+        mint.decimals,
+    )
+}
+
+pub fn close_token_account<'info>(
+    token_program: &Interface<'info, TokenInterface>,
+    account: &InterfaceAccount<'info, TokenAccount>,
+    destination: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    token_interface::close_account(CpiContext::new_with_signer(
+        token_program.to_account_info(),
+        token_interface::CloseAccount {
+            account: account.to_account_info(),
+            destination: destination.clone(),
+            authority: authority.clone(),
+        },
+        signer_seeds,
+    ))
+}
+
+pub fn create_pda_account<'info>(
+    system_program: &Program<'info, System>,
+    payer: &AccountInfo<'info>,
+    account: &AccountInfo<'info>,
+    space: usize,
+    owner: &Pubkey,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let rent = Rent::get()?.minimum_balance(space);
+    let lamports = account.lamports();
+
+    if lamports == 0 {
+        return system_program::create_account(
+            CpiContext::new_with_signer(
+                system_program.to_account_info(),
+                system_program::CreateAccount {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+                signer_seeds,
+            ),
+            rent,
+            space as u64,
+            owner,
+        );
+    }
+
+    // create_account fails on a prefunded address, so it is topped up, allocated and assigned
+    // instead, the same way anchor handles `init`
+    if lamports < rent {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.to_account_info(),
+                system_program::Transfer {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+            ),
+            rent - lamports,
+        )?;
+    }
+
+    system_program::allocate(
+        CpiContext::new_with_signer(
+            system_program.to_account_info(),
+            system_program::Allocate {
+                account_to_allocate: account.clone(),
+            },
+            signer_seeds,
+        ),
+        space as u64,
+    )?;
+
+    system_program::assign(
+        CpiContext::new_with_signer(
+            system_program.to_account_info(),
+            system_program::Assign {
+                account_to_assign: account.clone(),
+            },
+            signer_seeds,
+        ),
+        owner,
+    )
+}
+
 pub fn mint_to<'info>(
     token_program: &Interface<'info, TokenInterface>,
     mint: &InterfaceAccount<'info, Mint>,
@@ -29,6 +165,27 @@ pub fn mint_to<'info>(
     )
 }
 
+pub fn metadata_creators(creators: &[EventCreator], authority: Pubkey) -> Vec<Creator> {
+    // the collection mint is the only creator when none are provided
+    if creators.is_empty() {
+        return vec![Creator {
+            address: authority,
+            verified: true,
+            share: 100,
+        }];
+    }
+
+    creators
+        .iter()
+        .map(|creator| Creator {
+            address: creator.address,
+            verified: creator.address == authority,
+            share: creator.share,
+        })
+        .collect()
+}
+
+#[allow(clippy::too_many_arguments)]
 pub fn create_metadata_accounts<'info>(
     metadata_program: &Program<'info, Metadata>,
     metadata_account: &UncheckedAccount<'info>,
@@ -42,6 +199,7 @@ pub fn create_metadata_accounts<'info>(
     symbol: String,
     uri: String,
     seller_fee_basis_points: u16,
+    creators: &[EventCreator],
     collection: Option<Collection>,
     is_mutable: bool,
     collection_details: Option<CollectionDetails>,
@@ -64,15 +222,8 @@ pub fn create_metadata_accounts<'info>(
             name,
             symbol,
             uri,
-            seller_fee_basis_points, // TODO (fran): CHECK
-            creators: Some(vec![
-                // TODO (fran): CHECK
-                Creator {
-                    address: authority.key(),
-                    verified: true,
-                    share: 100,
-                },
-            ]),
+            seller_fee_basis_points,
+            creators: Some(metadata_creators(creators, authority.key())),
             collection,
             uses: None,
         },
@@ -82,6 +233,7 @@ pub fn create_metadata_accounts<'info>(
     )
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn create_master_edition<'info>(
     metadata_program: &Program<'info, Metadata>,
     edition_account: &UncheckedAccount<'info>,
@@ -140,3 +292,219 @@ pub fn set_and_verify_sized_collection_item<'info>(
         None,
     )
 }
+
+#[allow(clippy::too_many_arguments)]
+pub fn burn_nft<'info>(
+    metadata_program: &Program<'info, Metadata>,
+    metadata_account: &UncheckedAccount<'info>,
+    owner: &Signer<'info>,
+    mint: &InterfaceAccount<'info, Mint>,
+    token_account: &InterfaceAccount<'info, TokenAccount>,
+    edition_account: &UncheckedAccount<'info>,
+    token_program: &Interface<'info, TokenInterface>,
+    collection_metadata: &UncheckedAccount<'info>,
+) -> Result<()> {
+    // anchor_spl::metadata::burn_nft does not forward the collection metadata account,
+    // which is required to burn a verified item of a sized collection
+    BurnNftCpi::new(
+        &metadata_program.to_account_info(),
+        BurnNftCpiAccounts {
+            metadata: &metadata_account.to_account_info(),
+            owner: &owner.to_account_info(),
+            mint: &mint.to_account_info(),
+            token_account: &token_account.to_account_info(),
+            master_edition_account: &edition_account.to_account_info(),
+            spl_token_program: &token_program.to_account_info(),
+            collection_metadata: Some(&collection_metadata.to_account_info()),
+        },
+    )
+    .invoke()
+    .map_err(Into::into)
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn freeze_token_account<'info>(
+    metadata_program: &Program<'info, Metadata>,
+    owner: &AccountInfo<'info>,
+    token_account: &InterfaceAccount<'info, TokenAccount>,
+    mint: &InterfaceAccount<'info, Mint>,
+    edition_account: &AccountInfo<'info>,
+    collection_mint: &InterfaceAccount<'info, Mint>,
+    token_program: &Interface<'info, TokenInterface>,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    // the master edition owns the freeze authority, so the collection mint freezes as delegate
+    token_interface::approve(
+        CpiContext::new(
+            token_program.to_account_info(),
+            token_interface::Approve {
+                to: token_account.to_account_info(),
+                delegate: collection_mint.to_account_info(),
+                authority: owner.clone(),
+            },
+        ),
+        1,
+    )?;
+
+    metadata::freeze_delegated_account(CpiContext::new_with_signer(
+        metadata_program.to_account_info(),
+        metadata::FreezeDelegatedAccount {
+            metadata: metadata_program.to_account_info(),
+            delegate: collection_mint.to_account_info(),
+            token_account: token_account.to_account_info(),
+            edition: edition_account.clone(),
+            mint: mint.to_account_info(),
+            token_program: token_program.to_account_info(),
+        },
+        signer_seeds,
+    ))
+}
+
+pub fn thaw_token_account<'info>(
+    metadata_program: &Program<'info, Metadata>,
+    token_account: &InterfaceAccount<'info, TokenAccount>,
+    mint: &InterfaceAccount<'info, Mint>,
+    edition_account: &AccountInfo<'info>,
+    collection_mint: &InterfaceAccount<'info, Mint>,
+    token_program: &Interface<'info, TokenInterface>,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    metadata::thaw_delegated_account(CpiContext::new_with_signer(
+        metadata_program.to_account_info(),
+        metadata::ThawDelegatedAccount {
+            metadata: metadata_program.to_account_info(),
+            delegate: collection_mint.to_account_info(),
+            token_account: token_account.to_account_info(),
+            edition: edition_account.clone(),
+            mint: mint.to_account_info(),
+            token_program: token_program.to_account_info(),
+        },
+        signer_seeds,
+    ))
+}
+
+// tlv header plus the token group and group member extension data
+const TOKEN_GROUP_TLV_SIZE: usize = 4 + 80;
+const TOKEN_GROUP_MEMBER_TLV_SIZE: usize = 4 + 72;
+
+// token-2022 reallocates the mint for variable length extensions but doesn't fund them
+fn fund_mint_extension<'info>(
+    system_program: &Program<'info, System>,
+    payer: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+    extension_size: usize,
+) -> Result<()> {
+    let required = Rent::get()?.minimum_balance(mint.data_len() + extension_size);
+    let missing = required.saturating_sub(mint.lamports());
+
+    if missing > 0 {
+        transfer_lamports(system_program, payer, mint, missing, &[])?;
+    }
+
+    Ok(())
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn initialize_token_2022_metadata<'info>(
+    token_program: &Interface<'info, TokenInterface>,
+    system_program: &Program<'info, System>,
+    payer: &AccountInfo<'info>,
+    mint: &InterfaceAccount<'info, Mint>,
+    authority: &InterfaceAccount<'info, Mint>,
+    signer_seeds: &[&[&[u8]]],
+    name: String,
+    symbol: String,
+    uri: String,
+) -> Result<()> {
+    let metadata = TokenMetadata {
+        update_authority: OptionalNonZeroPubkey(authority.key()),
+        mint: mint.key(),
+        name: name.clone(),
+        symbol: symbol.clone(),
+        uri: uri.clone(),
+        additional_metadata: vec![],
+    };
+    fund_mint_extension(
+        system_program,
+        payer,
+        &mint.to_account_info(),
+        metadata.tlv_size_of()?,
+    )?;
+
+    token_interface::token_metadata_initialize(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            token_interface::TokenMetadataInitialize {
+                program_id: token_program.to_account_info(),
+                metadata: mint.to_account_info(),
+                update_authority: authority.to_account_info(),
+                mint_authority: authority.to_account_info(),
+                mint: mint.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        name,
+        symbol,
+        uri,
+    )
+}
+
+pub fn initialize_token_2022_group<'info>(
+    token_program: &Interface<'info, TokenInterface>,
+    system_program: &Program<'info, System>,
+    payer: &AccountInfo<'info>,
+    collection_mint: &InterfaceAccount<'info, Mint>,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    fund_mint_extension(
+        system_program,
+        payer,
+        &collection_mint.to_account_info(),
+        TOKEN_GROUP_TLV_SIZE,
+    )?;
+
+    // supply is capped per digital access, so the group itself is unbounded
+    token_interface::token_group_initialize(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            token_interface::TokenGroupInitialize {
+                program_id: token_program.to_account_info(),
+                group: collection_mint.to_account_info(),
+                mint: collection_mint.to_account_info(),
+                mint_authority: collection_mint.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        Some(collection_mint.key()),
+        u64::MAX,
+    )
+}
+
+pub fn initialize_token_2022_member<'info>(
+    token_program: &Interface<'info, TokenInterface>,
+    system_program: &Program<'info, System>,
+    payer: &AccountInfo<'info>,
+    mint: &InterfaceAccount<'info, Mint>,
+    collection_mint: &InterfaceAccount<'info, Mint>,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    fund_mint_extension(
+        system_program,
+        payer,
+        &mint.to_account_info(),
+        TOKEN_GROUP_MEMBER_TLV_SIZE,
+    )?;
+
+    token_interface::token_member_initialize(CpiContext::new_with_signer(
+        token_program.to_account_info(),
+        token_interface::TokenMemberInitialize {
+            program_id: token_program.to_account_info(),
+            member: mint.to_account_info(),
+            member_mint: mint.to_account_info(),
+            member_mint_authority: collection_mint.to_account_info(),
+            group: collection_mint.to_account_info(),
+            group_update_authority: collection_mint.to_account_info(),
+        },
+        signer_seeds,
+    ))
+}
@@ -0,0 +1,80 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    metadata::Metadata,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+use crate::{DappError, Event, SEED_COLLECTION_MINT, SEED_EVENT, SEED_TOKEN_MINT};
+
+use super::thaw_token_account;
+
+#[derive(Accounts)]
+#[instruction(event_id: u64, token_id: u64)]
+pub struct ThawTicket<'info> {
+    pub creator: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_EVENT, event_id.to_le_bytes().as_ref()],
+        bump = event.bump,
+        has_one = creator @ DappError::InvalidCreator,
+    )]
+    pub event: Account<'info, Event>,
+
+    #[account(
+        seeds = [
+            SEED_TOKEN_MINT,
+            collection_mint.key().as_ref(),
+            token_id.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub token_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        token::mint = token_mint,
+        token::token_program = token_program,
+    )]
+    pub token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        seeds = [SEED_COLLECTION_MINT, event_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub collection_mint: InterfaceAccount<'info, Mint>,
+
+    /// CHECK: Validate address by deriving pda
+    #[account(
+        seeds = [
+            b"metadata",
+            token_metadata_program.key().as_ref(),
+            token_mint.key().as_ref(),
+            b"edition"
+        ],
+        bump,
+        seeds::program = token_metadata_program.key(),
+    )]
+    pub edition_account: UncheckedAccount<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub token_metadata_program: Program<'info, Metadata>,
+}
+
+pub fn process_thaw_ticket(ctx: Context<ThawTicket>, event_id: u64, _token_id: u64) -> Result<()> {
+    let acc = &ctx.accounts;
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        SEED_COLLECTION_MINT,
+        &event_id.to_le_bytes(),
+        &[ctx.bumps.collection_mint],
+    ]];
+
+    thaw_token_account(
+        &acc.token_metadata_program,
+        &acc.token_account,
+        &acc.token_mint,
+        &acc.edition_account,
+        &acc.collection_mint,
+        &acc.token_program,
+        signer_seeds,
+    )
+}
@@ -0,0 +1,124 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{metadata::Metadata, token_interface::Mint};
+
+use crate::{Config, DappError, Event, SEED_COLLECTION_MINT, SEED_CONFIG, SEED_EVENT};
+
+use super::{
+    update_compressed_uri, BubblegumMetadataArgs, UpdateMetadataAccounts,
+    ACCOUNT_COMPRESSION_PROGRAM_ID, BUBBLEGUM_PROGRAM_ID, NOOP_PROGRAM_ID,
+};
+
+#[derive(Accounts)]
+#[instruction(event_id: u64)]
+pub struct UpdateCompressedTokenMetadata<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_CONFIG],
+        bump = config.bump,
+        has_one = authority @ DappError::InvalidAuthority,
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        seeds = [SEED_EVENT, event_id.to_le_bytes().as_ref()],
+        bump = event.bump,
+        constraint = event.collection_mint_account == collection_mint.key(),
+        constraint = event.merkle_tree == Some(merkle_tree.key()) @ DappError::NotCompressedEvent,
+    )]
+    pub event: Box<Account<'info, Event>>,
+
+    #[account(
+        seeds = [SEED_COLLECTION_MINT, event_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub collection_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// CHECK: Validate address by deriving pda
+    #[account(
+        seeds = [
+            b"metadata",
+            token_metadata_program.key().as_ref(),
+            collection_mint.key().as_ref()
+        ],
+        bump,
+        seeds::program = token_metadata_program.key(),
+    )]
+    pub collection_metadata_account: UncheckedAccount<'info>,
+
+    /// CHECK: part of the leaf verified by bubblegum
+    pub leaf_owner: UncheckedAccount<'info>,
+
+    /// CHECK: part of the leaf verified by bubblegum
+    pub leaf_delegate: UncheckedAccount<'info>,
+
+    /// CHECK: checked against the event and modified by the account compression program
+    #[account(mut)]
+    pub merkle_tree: UncheckedAccount<'info>,
+
+    /// CHECK: Validate address by deriving pda
+    #[account(
+        seeds = [merkle_tree.key().as_ref()],
+        bump,
+        seeds::program = bubblegum_program.key(),
+    )]
+    pub tree_authority: UncheckedAccount<'info>,
+
+    /// CHECK: Validate address
+    #[account(address = BUBBLEGUM_PROGRAM_ID)]
+    pub bubblegum_program: UncheckedAccount<'info>,
+
+    /// CHECK: Validate address
+    #[account(address = ACCOUNT_COMPRESSION_PROGRAM_ID)]
+    pub compression_program: UncheckedAccount<'info>,
+
+    /// CHECK: Validate address
+    #[account(address = NOOP_PROGRAM_ID)]
+    pub log_wrapper: UncheckedAccount<'info>,
+
+    pub token_metadata_program: Program<'info, Metadata>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn process_update_compressed_token_metadata<'info>(
+    ctx: Context<'_, '_, '_, 'info, UpdateCompressedTokenMetadata<'info>>,
+    event_id: u64,
+    root: [u8; 32],
+    nonce: u64,
+    index: u32,
+    current_metadata: BubblegumMetadataArgs,
+    uri: String,
+) -> Result<()> {
+    let acc = &ctx.accounts;
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        SEED_COLLECTION_MINT,
+        &event_id.to_le_bytes(),
+        &[ctx.bumps.collection_mint],
+    ]];
+
+    // the proof nodes are passed as remaining accounts
+    update_compressed_uri(
+        UpdateMetadataAccounts {
+            bubblegum_program: &acc.bubblegum_program,
+            tree_authority: &acc.tree_authority,
+            collection_mint: &acc.collection_mint.to_account_info(),
+            collection_metadata: &acc.collection_metadata_account,
+            leaf_owner: &acc.leaf_owner,
+            leaf_delegate: &acc.leaf_delegate,
+            payer: &acc.authority,
+            merkle_tree: &acc.merkle_tree,
+            log_wrapper: &acc.log_wrapper,
+            compression_program: &acc.compression_program,
+            token_metadata_program: &acc.token_metadata_program,
+            system_program: &acc.system_program,
+            proof: ctx.remaining_accounts,
+        },
+        root,
+        nonce,
+        index,
+        current_metadata,
+        uri,
+        signer_seeds,
+    )
+}
@@ -0,0 +1,102 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    DappError, DigitalAccess, Event, EventRole, Role, SEED_DIGITAL_ACCESS, SEED_EVENT,
+    SEED_EVENT_ROLE,
+};
+
+#[derive(Accounts)]
+#[instruction(event_id: u64, digital_access_id: u8)]
+pub struct UpdateDigitalAccess<'info> {
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_EVENT, event_id.to_le_bytes().as_ref()],
+        bump = event.bump,
+    )]
+    pub event: Account<'info, Event>,
+
+    // only required when the signer is not the event creator
+    #[account(
+        seeds = [SEED_EVENT_ROLE, event.key().as_ref(), signer.key().as_ref()],
+        bump = event_role.bump,
+    )]
+    pub event_role: Option<Account<'info, EventRole>>,
+
+    #[account(
+        mut,
+        seeds = [
+            SEED_DIGITAL_ACCESS,
+            event.key().as_ref(),
+            digital_access_id.to_le_bytes().as_ref()
+        ],
+        bump = digital_access.bump,
+        has_one = event,
+    )]
+    pub digital_access: Account<'info, DigitalAccess>,
+}
+
+impl<'info> UpdateDigitalAccess<'info> {
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.event.has_role(
+                &self.signer.key(),
+                self.event_role.as_deref(),
+                Role::Manager
+            ),
+            DappError::MissingEventRole
+        );
+
+        Ok(())
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn process_update_digital_access(
+    ctx: Context<UpdateDigitalAccess>,
+    _event_id: u64,
+    _digital_access_id: u8,
+    price: Option<u64>,
+    max_supply: Option<u64>,
+    name: Option<String>,
+    description: Option<String>,
+    uri: Option<String>,
+) -> Result<()> {
+    ctx.accounts.validate()?;
+
+    let digital_access = &mut ctx.accounts.digital_access;
+
+    if let Some(price) = price {
+        digital_access.price = price;
+    }
+
+    if let Some(max_supply) = max_supply {
+        // more supply would reopen the auction after its buyers were told the clearing price
+        require!(
+            !digital_access.is_auction()
+                || (digital_access.current_minted == 0
+                    && !digital_access
+                        .is_auction_over(&ctx.accounts.event, Clock::get()?.unix_timestamp)),
+            DappError::AuctionSupplyLocked
+        );
+        require!(
+            max_supply >= digital_access.current_minted,
+            DappError::InvalidMaxSupply
+        );
+        digital_access.max_supply = max_supply;
+    }
+
+    if let Some(name) = name {
+        digital_access.name = name;
+    }
+
+    if let Some(description) = description {
+        digital_access.description = description;
+    }
+
+    if let Some(uri) = uri {
+        digital_access.uri = uri;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,119 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    metadata::{self, mpl_token_metadata::types::DataV2, Metadata, MetadataAccount},
+    token_interface::Mint,
+};
+
+use crate::{DappError, Event, EventRole, Role, SEED_COLLECTION_MINT, SEED_EVENT, SEED_EVENT_ROLE};
+
+#[derive(Accounts)]
+#[instruction(event_id: u64)]
+pub struct UpdateEvent<'info> {
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED_EVENT, event_id.to_le_bytes().as_ref()],
+        bump = event.bump,
+        constraint = event.collection_mint_account == collection_mint.key(),
+        constraint = !event.token_2022 @ DappError::Token2022Event,
+    )]
+    pub event: Account<'info, Event>,
+
+    // only required when the signer is not the event creator
+    #[account(
+        seeds = [SEED_EVENT_ROLE, event.key().as_ref(), signer.key().as_ref()],
+        bump = event_role.bump,
+    )]
+    pub event_role: Option<Account<'info, EventRole>>,
+
+    #[account(
+        seeds = [SEED_COLLECTION_MINT, event_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub collection_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"metadata",
+            token_metadata_program.key().as_ref(),
+            collection_mint.key().as_ref()
+        ],
+        bump,
+        seeds::program = token_metadata_program.key(),
+    )]
+    pub collection_metadata_account: Account<'info, MetadataAccount>,
+
+    pub token_metadata_program: Program<'info, Metadata>,
+}
+
+impl<'info> UpdateEvent<'info> {
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.event.has_role(
+                &self.signer.key(),
+                self.event_role.as_deref(),
+                Role::Manager
+            ),
+            DappError::MissingEventRole
+        );
+
+        Ok(())
+    }
+}
+
+pub fn process_update_event(
+    ctx: Context<UpdateEvent>,
+    event_id: u64,
+    name: Option<String>,
+    symbol: Option<String>,
+    uri: Option<String>,
+    event_description: Option<String>,
+) -> Result<()> {
+    ctx.accounts.validate()?;
+
+    let acc = &ctx.accounts;
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        SEED_COLLECTION_MINT,
+        &event_id.to_le_bytes(),
+        &[ctx.bumps.collection_mint],
+    ]];
+
+    let metadata = &acc.collection_metadata_account;
+
+    metadata::update_metadata_accounts_v2(
+        CpiContext::new_with_signer(
+            acc.token_metadata_program.to_account_info(),
+            metadata::UpdateMetadataAccountsV2 {
+                metadata: metadata.to_account_info(),
+                update_authority: acc.collection_mint.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        None,
+        Some(DataV2 {
+            name: name.clone().unwrap_or_else(|| metadata.name.clone()),
+            symbol: symbol.unwrap_or_else(|| metadata.symbol.clone()),
+            uri: uri.unwrap_or_else(|| metadata.uri.clone()),
+            seller_fee_basis_points: metadata.seller_fee_basis_points,
+            creators: metadata.creators.clone(),
+            collection: metadata.collection.clone(),
+            uses: metadata.uses.clone(),
+        }),
+        None,
+        Some(true),
+    )?;
+
+    let event = &mut ctx.accounts.event;
+
+    if let Some(name) = name {
+        event.name = name;
+    }
+
+    if let Some(event_description) = event_description {
+        event.description = event_description;
+    }
+
+    Ok(())
+}
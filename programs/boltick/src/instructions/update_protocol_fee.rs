@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+
+use crate::{Config, DappError, MAX_BPS, SEED_CONFIG};
+
+#[derive(Accounts)]
+pub struct UpdateProtocolFee<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED_CONFIG],
+        bump = config.bump,
+        has_one = authority @ DappError::InvalidAuthority,
+    )]
+    pub config: Account<'info, Config>,
+}
+
+pub fn process_update_protocol_fee(
+    ctx: Context<UpdateProtocolFee>,
+    protocol_fee_bps: u16,
+) -> Result<()> {
+    require!(protocol_fee_bps <= MAX_BPS, DappError::InvalidBasisPoints);
+
+    ctx.accounts.config.protocol_fee_bps = protocol_fee_bps;
+
+    Ok(())
+}
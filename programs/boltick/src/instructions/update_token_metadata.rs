@@ -108,7 +108,7 @@ pub fn process_update_token_metadata(
             name: acc.metadata_account.name.clone(),
             symbol: acc.metadata_account.symbol.clone(),
             uri,
-            seller_fee_basis_points: acc.metadata_account.seller_fee_basis_points.clone(),
+            seller_fee_basis_points: acc.metadata_account.seller_fee_basis_points,
             creators: acc.metadata_account.creators.clone(),
             collection: acc.metadata_account.collection.clone(),
             uses: acc.metadata_account.uses.clone(),
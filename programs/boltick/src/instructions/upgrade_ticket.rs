@@ -0,0 +1,309 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    metadata::{self, mpl_token_metadata::types::DataV2, Metadata, MetadataAccount},
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+use crate::{
+    Config, DappError, DigitalAccess, Event, Ticket, SEED_COLLECTION_MINT, SEED_CONFIG,
+    SEED_DIGITAL_ACCESS, SEED_EVENT, SEED_REDEMPTION, SEED_TICKET, SEED_TOKEN_MINT, SEED_TREASURY,
+    SEED_VAULT,
+};
+
+use super::{freeze_token_account, thaw_token_account, transfer_lamports};
+
+#[derive(Accounts)]
+#[instruction(event_id: u64, token_id: u64, digital_access_id: u8)]
+pub struct UpgradeTicket<'info> {
+    #[account(mut)]
+    pub holder: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_CONFIG],
+        bump = config.bump,
+        constraint = !config.paused @ DappError::ProgramPaused,
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        seeds = [SEED_EVENT, event_id.to_le_bytes().as_ref()],
+        bump = event.bump,
+        constraint = event.collection_mint_account == collection_mint.key(),
+        constraint = !event.paused @ DappError::EventPaused,
+    )]
+    pub event: Box<Account<'info, Event>>,
+
+    #[account(mut, address = event.creator)]
+    pub event_creator: SystemAccount<'info>,
+
+    #[account(mut, address = ticket.digital_access)]
+    pub from_digital_access: Box<Account<'info, DigitalAccess>>,
+
+    #[account(
+        mut,
+        seeds = [
+            SEED_DIGITAL_ACCESS,
+            event.key().as_ref(),
+            digital_access_id.to_le_bytes().as_ref()
+        ],
+        bump = to_digital_access.bump,
+        has_one = event,
+        constraint = !to_digital_access.is_sold_out() @ DappError::MaxSupplyReached,
+    )]
+    pub to_digital_access: Box<Account<'info, DigitalAccess>>,
+
+    #[account(
+        mut,
+        seeds = [SEED_TREASURY],
+        bump = config.treasury_bump
+    )]
+    pub treasury: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED_VAULT, event.key().as_ref()],
+        bump = event.vault_bump
+    )]
+    pub vault: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED_TICKET, token_mint.key().as_ref()],
+        bump = ticket.bump,
+        has_one = event,
+    )]
+    pub ticket: Box<Account<'info, Ticket>>,
+
+    #[account(
+        seeds = [
+            SEED_TOKEN_MINT,
+            collection_mint.key().as_ref(),
+            token_id.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = holder,
+        associated_token::token_program = token_program,
+        constraint = holder_token_account.amount == 1 @ DappError::TicketNotOwned,
+    )]
+    pub holder_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: Validate address by deriving pda, it only exists once the ticket is redeemed
+    #[account(
+        seeds = [SEED_REDEMPTION, token_mint.key().as_ref()],
+        bump,
+        constraint = redemption.data_is_empty() @ DappError::TicketAlreadyRedeemed,
+    )]
+    pub redemption: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [SEED_COLLECTION_MINT, event_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub collection_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"metadata",
+            token_metadata_program.key().as_ref(),
+            token_mint.key().as_ref()
+        ],
+        bump,
+        seeds::program = token_metadata_program.key(),
+    )]
+    pub metadata_account: Box<Account<'info, MetadataAccount>>,
+
+    /// CHECK: Validate address by deriving pda
+    #[account(
+        seeds = [
+            b"metadata",
+            token_metadata_program.key().as_ref(),
+            token_mint.key().as_ref(),
+            b"edition"
+        ],
+        bump,
+        seeds::program = token_metadata_program.key(),
+    )]
+    pub edition_account: UncheckedAccount<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub token_metadata_program: Program<'info, Metadata>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> UpgradeTicket<'info> {
+    pub fn validate(&self) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+
+        require!(self.event.is_on_sale(now), DappError::EventNotActive);
+        require!(self.event.merkle_tree.is_none(), DappError::CompressedEvent);
+        require!(!self.event.token_2022, DappError::Token2022Event);
+        require!(
+            self.to_digital_access.is_on_sale(now) && !self.to_digital_access.is_in_presale(now),
+            DappError::SaleNotActive
+        );
+        require!(
+            self.to_digital_access.waitlist_len == 0,
+            DappError::WaitlistNotEmpty
+        );
+        // the price difference is paid in SOL
+        require!(
+            self.from_digital_access.payment_mint.is_none()
+                && self.to_digital_access.payment_mint.is_none(),
+            DappError::UnsupportedPaymentMint
+        );
+        // auction rebates and per-tier wallet limits are tracked against the original tier
+        require!(
+            self.from_digital_access.key() != self.to_digital_access.key()
+                && !self.from_digital_access.is_auction()
+                && !self.to_digital_access.is_auction()
+                && self.to_digital_access.max_per_wallet.is_none(),
+            DappError::InvalidUpgrade
+        );
+
+        Ok(())
+    }
+
+    pub fn collect_payment(&self, amount: u64) -> Result<()> {
+        // escrowed proceeds stay whole in the vault, the fee is taken on settlement
+        let fee = if self.event.escrow {
+            0
+        } else {
+            self.config.protocol_fee(amount)?
+        };
+
+        // transfer protocol fee to treasury
+        if fee > 0 {
+            transfer_lamports(
+                &self.system_program,
+                &self.holder.to_account_info(),
+                &self.treasury.to_account_info(),
+                fee,
+                &[],
+            )?;
+        }
+
+        // transfer SOL to event creator or vault
+        let destination = if self.event.escrow {
+            self.vault.to_account_info()
+        } else {
+            self.event_creator.to_account_info()
+        };
+
+        transfer_lamports(
+            &self.system_program,
+            &self.holder.to_account_info(),
+            &destination,
+            amount - fee,
+            &[],
+        )
+    }
+
+    pub fn update_metadata(&self, token_id: u64, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+        metadata::update_metadata_accounts_v2(
+            CpiContext::new_with_signer(
+                self.token_metadata_program.to_account_info(),
+                metadata::UpdateMetadataAccountsV2 {
+                    metadata: self.metadata_account.to_account_info(),
+                    update_authority: self.collection_mint.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            None,
+            Some(DataV2 {
+                name: format!("{} #{}", self.to_digital_access.name, token_id),
+                symbol: self.to_digital_access.symbol.clone(),
+                uri: self.to_digital_access.uri.clone(),
+                seller_fee_basis_points: self.metadata_account.seller_fee_basis_points,
+                creators: self.metadata_account.creators.clone(),
+                collection: self.metadata_account.collection.clone(),
+                uses: self.metadata_account.uses.clone(),
+            }),
+            None,
+            None,
+        )
+    }
+
+    // the ticket follows the transferability of its new tier
+    pub fn update_freeze_state(&self, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+        let frozen = self.holder_token_account.is_frozen();
+
+        if frozen && self.to_digital_access.transferable {
+            return thaw_token_account(
+                &self.token_metadata_program,
+                &self.holder_token_account,
+                &self.token_mint,
+                &self.edition_account,
+                &self.collection_mint,
+                &self.token_program,
+                signer_seeds,
+            );
+        }
+
+        if !frozen && !self.to_digital_access.transferable {
+            return freeze_token_account(
+                &self.token_metadata_program,
+                &self.holder.to_account_info(),
+                &self.holder_token_account,
+                &self.token_mint,
+                &self.edition_account,
+                &self.collection_mint,
+                &self.token_program,
+                signer_seeds,
+            );
+        }
+
+        Ok(())
+    }
+}
+
+pub fn process_upgrade_ticket(
+    ctx: Context<UpgradeTicket>,
+    event_id: u64,
+    token_id: u64,
+    _digital_access_id: u8,
+    max_price: u64,
+) -> Result<()> {
+    ctx.accounts.validate()?;
+
+    let price = ctx
+        .accounts
+        .to_digital_access
+        .current_price(Clock::get()?.unix_timestamp)?;
+    require!(
+        price > ctx.accounts.ticket.price_paid,
+        DappError::InvalidUpgrade
+    );
+
+    // only the difference to the price already paid is charged
+    let amount = price - ctx.accounts.ticket.price_paid;
+    require!(amount <= max_price, DappError::PriceAboveMax);
+
+    ctx.accounts.collect_payment(amount)?;
+
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        SEED_COLLECTION_MINT,
+        &event_id.to_le_bytes(),
+        &[ctx.bumps.collection_mint],
+    ]];
+
+    ctx.accounts.update_metadata(token_id, signer_seeds)?;
+    ctx.accounts.update_freeze_state(signer_seeds)?;
+
+    let to_digital_access_key = ctx.accounts.to_digital_access.key();
+    let ticket = &mut ctx.accounts.ticket;
+    ticket.digital_access = to_digital_access_key;
+    ticket.price_paid = price;
+    ticket.base_price = price;
+
+    ctx.accounts.from_digital_access.current_minted -= 1;
+    ctx.accounts.to_digital_access.current_minted += 1;
+
+    Ok(())
+}
@@ -0,0 +1,131 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{Config, DappError, SEED_CONFIG, SEED_TREASURY};
+
+use super::{transfer_lamports, transfer_tokens};
+
+#[derive(Accounts)]
+pub struct WithdrawTreasury<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_CONFIG],
+        bump = config.bump,
+        has_one = authority @ DappError::InvalidAuthority,
+        has_one = treasury,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [SEED_TREASURY],
+        bump = config.treasury_bump
+    )]
+    pub treasury: SystemAccount<'info>,
+
+    #[account(mut)]
+    pub destination: SystemAccount<'info>,
+
+    // payment accounts, only required to withdraw the fees of a payment mint
+    #[account(mint::token_program = payment_token_program)]
+    pub payment_mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+
+    #[account(
+        mut,
+        associated_token::mint = payment_mint,
+        associated_token::authority = treasury,
+        associated_token::token_program = payment_token_program,
+    )]
+    pub treasury_payment_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(
+        mut,
+        associated_token::mint = payment_mint,
+        associated_token::authority = destination,
+        associated_token::token_program = payment_token_program,
+    )]
+    pub destination_payment_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    pub payment_token_program: Option<Interface<'info, TokenInterface>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> WithdrawTreasury<'info> {
+    pub fn validate(&self, amount: u64) -> Result<()> {
+        require!(amount > 0, DappError::ZeroAmount);
+
+        let available = match &self.payment_mint {
+            Some(payment_mint) => {
+                let (Some(treasury_payment_token_account), Some(_)) = (
+                    &self.treasury_payment_token_account,
+                    &self.destination_payment_token_account,
+                ) else {
+                    return err!(DappError::MissingPaymentAccounts);
+                };
+                require_keys_eq!(
+                    treasury_payment_token_account.mint,
+                    payment_mint.key(),
+                    DappError::PaymentMintMismatch
+                );
+
+                treasury_payment_token_account.amount
+            }
+            // the treasury must stay rent exempt
+            None => self
+                .treasury
+                .lamports()
+                .saturating_sub(Rent::get()?.minimum_balance(0)),
+        };
+        require!(amount <= available, DappError::InsufficientTreasuryFunds);
+
+        Ok(())
+    }
+
+    pub fn withdraw_tokens(&self, amount: u64, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+        let (
+            Some(payment_mint),
+            Some(treasury_payment_token_account),
+            Some(destination_payment_token_account),
+            Some(payment_token_program),
+        ) = (
+            &self.payment_mint,
+            &self.treasury_payment_token_account,
+            &self.destination_payment_token_account,
+            &self.payment_token_program,
+        )
+        else {
+            return err!(DappError::MissingPaymentAccounts);
+        };
+
+        transfer_tokens(
+            payment_token_program,
+            treasury_payment_token_account,
+            destination_payment_token_account,
+            &self.treasury.to_account_info(),
+            payment_mint,
+            amount,
+            signer_seeds,
+        )
+    }
+}
+
+pub fn process_withdraw_treasury(ctx: Context<WithdrawTreasury>, amount: u64) -> Result<()> {
+    ctx.accounts.validate(amount)?;
+
+    let acc = &ctx.accounts;
+    let signer_seeds: &[&[&[u8]]] = &[&[SEED_TREASURY, &[acc.config.treasury_bump]]];
+
+    if acc.payment_mint.is_some() {
+        return acc.withdraw_tokens(amount, signer_seeds);
+    }
+
+    transfer_lamports(
+        &acc.system_program,
+        &acc.treasury.to_account_info(),
+        &acc.destination.to_account_info(),
+        amount,
+        signer_seeds,
+    )
+}
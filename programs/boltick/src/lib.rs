@@ -20,16 +20,51 @@ pub mod boltick {
         process_initialize_config(ctx)
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn initialize_event(
         ctx: Context<InitializeEvent>,
         name: String,
         symbol: String,
         uri: String,
         event_description: String,
+        start_time: i64,
+        end_time: i64,
+        sales_start: i64,
+        sales_end: i64,
+        escrow: bool,
+        seller_fee_basis_points: u16,
+        creators: Vec<EventCreator>,
+        max_per_wallet: Option<u64>,
     ) -> Result<()> {
-        process_initialize_event(ctx, name, symbol, uri, event_description)
+        process_initialize_event(
+            ctx,
+            name,
+            symbol,
+            uri,
+            event_description,
+            start_time,
+            end_time,
+            sales_start,
+            sales_end,
+            escrow,
+            seller_fee_basis_points,
+            creators,
+            max_per_wallet,
+        )
     }
 
+    pub fn update_event(
+        ctx: Context<UpdateEvent>,
+        event_id: u64,
+        name: Option<String>,
+        symbol: Option<String>,
+        uri: Option<String>,
+        event_description: Option<String>,
+    ) -> Result<()> {
+        process_update_event(ctx, event_id, name, symbol, uri, event_description)
+    }
+
+    #[allow(clippy::too_many_arguments)]
     pub fn add_digital_access(
         ctx: Context<AddDigitalAccess>,
         event_id: u64,
@@ -39,6 +74,11 @@ pub mod boltick {
         symbol: String,
         description: String,
         uri: String,
+        sale_start: Option<i64>,
+        sale_end: Option<i64>,
+        max_resale_bps: Option<u16>,
+        transferable: bool,
+        max_per_wallet: Option<u64>,
     ) -> Result<()> {
         process_add_digital_access(
             ctx,
@@ -49,15 +89,94 @@ pub mod boltick {
             symbol,
             description,
             uri,
+            sale_start,
+            sale_end,
+            max_resale_bps,
+            transferable,
+            max_per_wallet,
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn update_digital_access(
+        ctx: Context<UpdateDigitalAccess>,
+        event_id: u64,
+        digital_access_id: u8,
+        price: Option<u64>,
+        max_supply: Option<u64>,
+        name: Option<String>,
+        description: Option<String>,
+        uri: Option<String>,
+    ) -> Result<()> {
+        process_update_digital_access(
+            ctx,
+            event_id,
+            digital_access_id,
+            price,
+            max_supply,
+            name,
+            description,
+            uri,
         )
     }
 
+    pub fn close_digital_access(
+        ctx: Context<CloseDigitalAccess>,
+        event_id: u64,
+        digital_access_id: u8,
+    ) -> Result<()> {
+        process_close_digital_access(ctx, event_id, digital_access_id)
+    }
+
     pub fn mint_token(ctx: Context<MintToken>, event_id: u64, digital_access_id: u8) -> Result<()> {
         process_mint_token(ctx, event_id, digital_access_id)
     }
 
-    pub fn buy_token(ctx: Context<BuyToken>, event_id: u64, digital_access_id: u8) -> Result<()> {
-        process_buy_token(ctx, event_id, digital_access_id)
+    pub fn mint_tokens_batch<'info>(
+        ctx: Context<'_, '_, 'info, 'info, MintTokensBatch<'info>>,
+        event_id: u64,
+        digital_access_id: u8,
+        quantity: u8,
+    ) -> Result<()> {
+        process_mint_tokens_batch(ctx, event_id, digital_access_id, quantity)
+    }
+
+    pub fn buy_token(
+        ctx: Context<BuyToken>,
+        event_id: u64,
+        digital_access_id: u8,
+        proof: Option<Vec<[u8; 32]>>,
+        allocation: Option<u64>,
+        discount_code: Option<String>,
+        max_price: u64,
+    ) -> Result<()> {
+        process_buy_token(
+            ctx,
+            event_id,
+            digital_access_id,
+            proof,
+            allocation,
+            discount_code,
+            max_price,
+        )
+    }
+
+    pub fn set_presale(
+        ctx: Context<SetPresale>,
+        event_id: u64,
+        digital_access_id: u8,
+        presale_root: Option<[u8; 32]>,
+        presale_start: Option<i64>,
+        presale_end: Option<i64>,
+    ) -> Result<()> {
+        process_set_presale(
+            ctx,
+            event_id,
+            digital_access_id,
+            presale_root,
+            presale_start,
+            presale_end,
+        )
     }
 
     pub fn update_token_metadata(
@@ -68,4 +187,278 @@ pub mod boltick {
     ) -> Result<()> {
         process_update_token_metadata(ctx, event_id, token_id, uri)
     }
+
+    pub fn grant_role(
+        ctx: Context<GrantRole>,
+        event_id: u64,
+        wallet: Pubkey,
+        role: Role,
+    ) -> Result<()> {
+        process_grant_role(ctx, event_id, wallet, role)
+    }
+
+    pub fn revoke_role(
+        ctx: Context<RevokeRole>,
+        event_id: u64,
+        wallet: Pubkey,
+        role: Role,
+    ) -> Result<()> {
+        process_revoke_role(ctx, event_id, wallet, role)
+    }
+
+    pub fn redeem_token(ctx: Context<RedeemToken>, event_id: u64, token_id: u64) -> Result<()> {
+        process_redeem_token(ctx, event_id, token_id)
+    }
+
+    pub fn update_protocol_fee(
+        ctx: Context<UpdateProtocolFee>,
+        protocol_fee_bps: u16,
+    ) -> Result<()> {
+        process_update_protocol_fee(ctx, protocol_fee_bps)
+    }
+
+    pub fn withdraw_treasury(ctx: Context<WithdrawTreasury>, amount: u64) -> Result<()> {
+        process_withdraw_treasury(ctx, amount)
+    }
+
+    pub fn set_paused(ctx: Context<SetPaused>, paused: bool) -> Result<()> {
+        process_set_paused(ctx, paused)
+    }
+
+    pub fn set_event_paused(
+        ctx: Context<SetEventPaused>,
+        event_id: u64,
+        paused: bool,
+    ) -> Result<()> {
+        process_set_event_paused(ctx, event_id, paused)
+    }
+
+    pub fn propose_authority(ctx: Context<ProposeAuthority>, new_authority: Pubkey) -> Result<()> {
+        process_propose_authority(ctx, new_authority)
+    }
+
+    pub fn accept_authority(ctx: Context<AcceptAuthority>) -> Result<()> {
+        process_accept_authority(ctx)
+    }
+
+    pub fn cancel_event(ctx: Context<CancelEvent>, event_id: u64) -> Result<()> {
+        process_cancel_event(ctx, event_id)
+    }
+
+    pub fn claim_refund(ctx: Context<ClaimRefund>, event_id: u64, token_id: u64) -> Result<()> {
+        process_claim_refund(ctx, event_id, token_id)
+    }
+
+    pub fn settle_event<'info>(
+        ctx: Context<'_, '_, 'info, 'info, SettleEvent<'info>>,
+        event_id: u64,
+    ) -> Result<()> {
+        process_settle_event(ctx, event_id)
+    }
+
+    pub fn list_ticket(
+        ctx: Context<ListTicket>,
+        event_id: u64,
+        token_id: u64,
+        price: u64,
+    ) -> Result<()> {
+        process_list_ticket(ctx, event_id, token_id, price)
+    }
+
+    pub fn cancel_listing(ctx: Context<CancelListing>) -> Result<()> {
+        process_cancel_listing(ctx)
+    }
+
+    pub fn buy_listing<'info>(ctx: Context<'_, '_, '_, 'info, BuyListing<'info>>) -> Result<()> {
+        process_buy_listing(ctx)
+    }
+
+    pub fn freeze_ticket(ctx: Context<FreezeTicket>, event_id: u64, token_id: u64) -> Result<()> {
+        process_freeze_ticket(ctx, event_id, token_id)
+    }
+
+    pub fn thaw_ticket(ctx: Context<ThawTicket>, event_id: u64, token_id: u64) -> Result<()> {
+        process_thaw_ticket(ctx, event_id, token_id)
+    }
+
+    pub fn create_ticket_tree<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CreateTicketTree<'info>>,
+        event_id: u64,
+        max_depth: u32,
+        max_buffer_size: u32,
+    ) -> Result<()> {
+        process_create_ticket_tree(ctx, event_id, max_depth, max_buffer_size)
+    }
+
+    pub fn buy_compressed_token(
+        ctx: Context<BuyCompressedToken>,
+        event_id: u64,
+        digital_access_id: u8,
+        max_price: u64,
+    ) -> Result<()> {
+        process_buy_compressed_token(ctx, event_id, digital_access_id, max_price)
+    }
+
+    pub fn redeem_compressed_token<'info>(
+        ctx: Context<'_, '_, '_, 'info, RedeemCompressedToken<'info>>,
+        event_id: u64,
+        root: [u8; 32],
+        data_hash: [u8; 32],
+        creator_hash: [u8; 32],
+        nonce: u64,
+        index: u32,
+    ) -> Result<()> {
+        process_redeem_compressed_token(ctx, event_id, root, data_hash, creator_hash, nonce, index)
+    }
+
+    pub fn update_compressed_token_metadata<'info>(
+        ctx: Context<'_, '_, '_, 'info, UpdateCompressedTokenMetadata<'info>>,
+        event_id: u64,
+        root: [u8; 32],
+        nonce: u64,
+        index: u32,
+        current_metadata: BubblegumMetadataArgs,
+        uri: String,
+    ) -> Result<()> {
+        process_update_compressed_token_metadata(
+            ctx,
+            event_id,
+            root,
+            nonce,
+            index,
+            current_metadata,
+            uri,
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn initialize_token_2022_event(
+        ctx: Context<InitializeToken2022Event>,
+        name: String,
+        symbol: String,
+        uri: String,
+        event_description: String,
+        start_time: i64,
+        end_time: i64,
+        sales_start: i64,
+        sales_end: i64,
+        seller_fee_basis_points: u16,
+        creators: Vec<EventCreator>,
+    ) -> Result<()> {
+        process_initialize_token_2022_event(
+            ctx,
+            name,
+            symbol,
+            uri,
+            event_description,
+            start_time,
+            end_time,
+            sales_start,
+            sales_end,
+            seller_fee_basis_points,
+            creators,
+        )
+    }
+
+    pub fn buy_token_2022(
+        ctx: Context<BuyToken2022>,
+        event_id: u64,
+        digital_access_id: u8,
+        max_price: u64,
+    ) -> Result<()> {
+        process_buy_token_2022(ctx, event_id, digital_access_id, max_price)
+    }
+
+    pub fn burn_ticket(ctx: Context<BurnTicket>, event_id: u64, token_id: u64) -> Result<()> {
+        process_burn_ticket(ctx, event_id, token_id)
+    }
+
+    pub fn close_event<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CloseEvent<'info>>,
+        event_id: u64,
+    ) -> Result<()> {
+        process_close_event(ctx, event_id)
+    }
+
+    pub fn create_discount(
+        ctx: Context<CreateDiscount>,
+        event_id: u64,
+        code_hash: [u8; 32],
+        kind: DiscountKind,
+        max_uses: u64,
+        expires_at: Option<i64>,
+        digital_access_id: Option<u8>,
+    ) -> Result<()> {
+        process_create_discount(
+            ctx,
+            event_id,
+            code_hash,
+            kind,
+            max_uses,
+            expires_at,
+            digital_access_id,
+        )
+    }
+
+    pub fn close_discount(
+        ctx: Context<CloseDiscount>,
+        event_id: u64,
+        code_hash: [u8; 32],
+    ) -> Result<()> {
+        process_close_discount(ctx, event_id, code_hash)
+    }
+
+    pub fn set_pricing(
+        ctx: Context<SetPricing>,
+        event_id: u64,
+        digital_access_id: u8,
+        pricing: PricingMode,
+    ) -> Result<()> {
+        process_set_pricing(ctx, event_id, digital_access_id, pricing)
+    }
+
+    pub fn claim_rebate(
+        ctx: Context<ClaimRebate>,
+        event_id: u64,
+        digital_access_id: u8,
+        token_id: u64,
+    ) -> Result<()> {
+        process_claim_rebate(ctx, event_id, digital_access_id, token_id)
+    }
+
+    pub fn join_waitlist(
+        ctx: Context<JoinWaitlist>,
+        event_id: u64,
+        digital_access_id: u8,
+    ) -> Result<()> {
+        process_join_waitlist(ctx, event_id, digital_access_id)
+    }
+
+    pub fn leave_waitlist(
+        ctx: Context<LeaveWaitlist>,
+        event_id: u64,
+        digital_access_id: u8,
+        position: u64,
+    ) -> Result<()> {
+        process_leave_waitlist(ctx, event_id, digital_access_id, position)
+    }
+
+    pub fn process_waitlist<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ProcessWaitlist<'info>>,
+        event_id: u64,
+        digital_access_id: u8,
+        position: u64,
+    ) -> Result<()> {
+        process_process_waitlist(ctx, event_id, digital_access_id, position)
+    }
+
+    pub fn upgrade_ticket(
+        ctx: Context<UpgradeTicket>,
+        event_id: u64,
+        token_id: u64,
+        digital_access_id: u8,
+        max_price: u64,
+    ) -> Result<()> {
+        process_upgrade_ticket(ctx, event_id, token_id, digital_access_id, max_price)
+    }
 }
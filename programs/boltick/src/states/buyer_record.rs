@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+
+use crate::{DappError, MAX_BUYER_TIERS};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct TierPurchase {
+    pub digital_access_id: u8,
+    pub purchased: u64,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct BuyerRecord {
+    pub event: Pubkey,
+    pub buyer: Pubkey,
+    pub purchased: u64,
+    // only tiers with a max_per_wallet limit are tracked
+    #[max_len(MAX_BUYER_TIERS)]
+    pub tiers: Vec<TierPurchase>,
+    pub bump: u8,
+}
+
+impl BuyerRecord {
+    pub fn add_tier_purchase(&mut self, digital_access_id: u8) -> Result<u64> {
+        if let Some(tier) = self
+            .tiers
+            .iter_mut()
+            .find(|tier| tier.digital_access_id == digital_access_id)
+        {
+            tier.purchased += 1;
+            return Ok(tier.purchased);
+        }
+
+        require!(
+            self.tiers.len() < MAX_BUYER_TIERS,
+            DappError::BuyerRecordFull
+        );
+
+        self.tiers.push(TierPurchase {
+            digital_access_id,
+            purchased: 1,
+        });
+
+        Ok(1)
+    }
+}
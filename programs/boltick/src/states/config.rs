@@ -1,11 +1,27 @@
 use anchor_lang::prelude::*;
 
+use crate::{DappError, MAX_BPS};
+
 #[account]
 #[derive(InitSpace)]
 pub struct Config {
     pub authority: Pubkey,
+    pub pending_authority: Option<Pubkey>,
     pub treasury: Pubkey,
     pub event_count: u64,
+    pub protocol_fee_bps: u16,
+    pub paused: bool,
     pub treasury_bump: u8,
     pub bump: u8,
 }
+
+impl Config {
+    pub fn protocol_fee(&self, amount: u64) -> Result<u64> {
+        let fee = (amount as u128)
+            .checked_mul(self.protocol_fee_bps as u128)
+            .ok_or(DappError::ArithmeticOverflow)?
+            / MAX_BPS as u128;
+
+        Ok(fee as u64)
+    }
+}
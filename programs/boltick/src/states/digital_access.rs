@@ -1,4 +1,31 @@
-use anchor_lang::prelude::*;
+use anchor_lang::{prelude::*, solana_program::hash::hashv};
+
+use crate::{DappError, Event, Ticket, MAX_BPS};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum PricingMode {
+    Flat,
+    // the price goes up by `increment` every `step_size` tokens sold
+    LinearStep {
+        step_size: u64,
+        increment: u64,
+    },
+    // the price goes up by `increment` every `interval` seconds after `start_time`
+    TimeStep {
+        start_time: i64,
+        interval: i64,
+        increment: u64,
+    },
+    // the price drops by `decrement` every `interval` seconds after `start_time` down to
+    // `floor_price`, buyers get the difference back once the auction clears
+    DutchAuction {
+        start_time: i64,
+        start_price: u64,
+        floor_price: u64,
+        interval: i64,
+        decrement: u64,
+    },
+}
 
 #[account]
 #[derive(InitSpace)]
@@ -6,9 +33,31 @@ pub struct DigitalAccess {
     pub event: Pubkey,
     pub id: u8,
     pub price: u64,
-    // pub used: bool,
+    // `price` is the base price of the curve
+    pub pricing: PricingMode,
+    // lowest auction price paid so far, the clearing price once the auction is over
+    pub clearing_price: u64,
+    // auction bids whose rebate is still unclaimed, summed at the price before discounts
+    pub unclaimed_bids: u64,
+    pub unclaimed_bidders: u64,
+    pub payment_mint: Option<Pubkey>,
     pub max_supply: u64,
     pub current_minted: u64,
+    // open waitlist entries sit between head and tail, `waitlist_len` counts them
+    pub waitlist_head: u64,
+    pub waitlist_tail: u64,
+    pub waitlist_len: u64,
+    pub sale_start: Option<i64>,
+    pub sale_end: Option<i64>,
+    // maximum markup over the face value allowed on resale
+    pub max_resale_bps: Option<u16>,
+    pub transferable: bool,
+    pub closed: bool,
+    pub max_per_wallet: Option<u64>,
+    // merkle root of the allowlist, buyers need a proof while the presale is open
+    pub presale_root: Option<[u8; 32]>,
+    pub presale_start: Option<i64>,
+    pub presale_end: Option<i64>,
     pub bump: u8,
     #[max_len(32)]
     pub name: String,
@@ -21,3 +70,185 @@ pub struct DigitalAccess {
     // #[max_len(160)]
     // pub uri_updated: String,
 }
+
+impl DigitalAccess {
+    pub fn is_valid_sale_window(sale_start: Option<i64>, sale_end: Option<i64>) -> bool {
+        match (sale_start, sale_end) {
+            (Some(start), Some(end)) => start < end,
+            _ => true,
+        }
+    }
+
+    pub fn is_valid_pricing(pricing: &PricingMode) -> bool {
+        match pricing {
+            PricingMode::Flat => true,
+            PricingMode::LinearStep {
+                step_size,
+                increment,
+            } => *step_size > 0 && *increment > 0,
+            PricingMode::TimeStep {
+                interval,
+                increment,
+                ..
+            } => *interval > 0 && *increment > 0,
+            PricingMode::DutchAuction {
+                start_price,
+                floor_price,
+                interval,
+                decrement,
+                ..
+            } => start_price > floor_price && *interval > 0 && *decrement > 0,
+        }
+    }
+
+    pub fn current_price(&self, now: i64) -> Result<u64> {
+        let (steps, increment) = match self.pricing {
+            PricingMode::Flat => return Ok(self.price),
+            PricingMode::LinearStep {
+                step_size,
+                increment,
+            } => (self.current_minted / step_size, increment),
+            PricingMode::TimeStep {
+                start_time,
+                interval,
+                increment,
+            } => (
+                (now - start_time).max(0) as u64 / interval as u64,
+                increment,
+            ),
+            PricingMode::DutchAuction {
+                start_time,
+                start_price,
+                floor_price,
+                interval,
+                decrement,
+            } => {
+                require!(now >= start_time, DappError::SaleNotActive);
+
+                let steps = (now - start_time) as u64 / interval as u64;
+                return Ok(start_price
+                    .saturating_sub(steps.saturating_mul(decrement))
+                    .max(floor_price));
+            }
+        };
+
+        steps
+            .checked_mul(increment)
+            .and_then(|markup| markup.checked_add(self.price))
+            .ok_or(DappError::ArithmeticOverflow.into())
+    }
+
+    pub fn is_auction(&self) -> bool {
+        matches!(self.pricing, PricingMode::DutchAuction { .. })
+    }
+
+    pub fn is_auction_over(&self, event: &Event, now: i64) -> bool {
+        self.current_minted >= self.max_supply
+            || self.closed
+            || now >= self.sale_end.unwrap_or(i64::MAX)
+            || now >= event.sales_end
+    }
+
+    // upper bound of the rebates still owed to auction buyers
+    pub fn outstanding_rebates(&self) -> Result<u64> {
+        let cleared = self
+            .unclaimed_bidders
+            .checked_mul(self.clearing_price)
+            .ok_or(DappError::ArithmeticOverflow)?;
+
+        Ok(self.unclaimed_bids.saturating_sub(cleared))
+    }
+
+    // a seat handed back before the event ends can be sold again or go to the waitlist
+    pub fn release_seat(&mut self, event: &Event, now: i64) {
+        if now <= event.end_time {
+            self.current_minted -= 1;
+        }
+    }
+
+    pub fn remove_unclaimed_bid(&mut self, base_price: u64) {
+        self.unclaimed_bids = self.unclaimed_bids.saturating_sub(base_price);
+        self.unclaimed_bidders = self.unclaimed_bidders.saturating_sub(1);
+    }
+
+    // a ticket leaving with an unclaimed rebate gives it up, it is no longer held back
+    pub fn forfeit_rebate(&mut self, ticket: &Ticket) {
+        if self.is_auction() && ticket.rebate(self.clearing_price) > 0 {
+            self.remove_unclaimed_bid(ticket.base_price);
+        }
+    }
+
+    pub fn is_valid_resale_price(&self, price: u64) -> Result<bool> {
+        let Some(max_resale_bps) = self.max_resale_bps else {
+            return Ok(true);
+        };
+
+        let markup = (self.price as u128)
+            .checked_mul(max_resale_bps as u128)
+            .ok_or(DappError::ArithmeticOverflow)?
+            / MAX_BPS as u128;
+
+        Ok(price as u128 <= self.price as u128 + markup)
+    }
+
+    pub fn is_sold_out(&self) -> bool {
+        self.current_minted >= self.max_supply
+    }
+
+    // processed tickets are minted to buyers that are not around to sign or pay again
+    pub fn supports_waitlist(&self) -> bool {
+        self.payment_mint.is_none()
+            && self.transferable
+            && self.max_per_wallet.is_none()
+            && !self.is_auction()
+    }
+
+    pub fn remove_waitlist_entry(&mut self) {
+        self.waitlist_len -= 1;
+
+        // nothing left to skip over once the queue is empty
+        if self.waitlist_len == 0 {
+            self.waitlist_head = self.waitlist_tail;
+        }
+    }
+
+    pub fn is_on_sale(&self, now: i64) -> bool {
+        !self.closed
+            && self.sale_start.unwrap_or(i64::MIN) <= now
+            && now < self.sale_end.unwrap_or(i64::MAX)
+    }
+
+    pub fn is_in_presale(&self, now: i64) -> bool {
+        !self.closed
+            && self.presale_root.is_some()
+            && self.presale_start.unwrap_or(i64::MIN) <= now
+            && now < self.presale_end.unwrap_or(i64::MAX)
+    }
+
+    // leaves are hash(buyer) or hash(buyer, allocation), pairs are hashed in sorted order
+    pub fn is_valid_presale_proof(
+        &self,
+        buyer: &Pubkey,
+        allocation: Option<u64>,
+        proof: &[[u8; 32]],
+    ) -> bool {
+        let Some(presale_root) = self.presale_root else {
+            return false;
+        };
+
+        let leaf = match allocation {
+            Some(allocation) => hashv(&[buyer.as_ref(), &allocation.to_le_bytes()]),
+            None => hashv(&[buyer.as_ref()]),
+        };
+
+        let root = proof.iter().fold(leaf.to_bytes(), |node, sibling| {
+            if node <= *sibling {
+                hashv(&[&node, sibling]).to_bytes()
+            } else {
+                hashv(&[sibling, &node]).to_bytes()
+            }
+        });
+
+        root == presale_root
+    }
+}
@@ -0,0 +1,57 @@
+use anchor_lang::{prelude::*, solana_program::hash::hashv};
+
+use crate::{DappError, MAX_BPS};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum DiscountKind {
+    Percentage { bps: u16 },
+    Fixed { amount: u64 },
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Discount {
+    pub event: Pubkey,
+    // sha256 of the code, buyers reveal the code itself when purchasing
+    pub code_hash: [u8; 32],
+    pub kind: DiscountKind,
+    pub max_uses: u64,
+    pub uses: u64,
+    pub expires_at: Option<i64>,
+    // restricts the discount to a single digital access when set
+    pub digital_access_id: Option<u8>,
+    pub bump: u8,
+}
+
+impl Discount {
+    pub fn is_valid_kind(kind: &DiscountKind) -> bool {
+        match kind {
+            DiscountKind::Percentage { bps } => *bps > 0 && *bps <= MAX_BPS,
+            DiscountKind::Fixed { amount } => *amount > 0,
+        }
+    }
+
+    pub fn is_valid_code(&self, code: &str) -> bool {
+        hashv(&[code.as_bytes()]).to_bytes() == self.code_hash
+    }
+
+    pub fn is_applicable(&self, now: i64, digital_access_id: u8) -> bool {
+        self.uses < self.max_uses
+            && now < self.expires_at.unwrap_or(i64::MAX)
+            && self.digital_access_id.unwrap_or(digital_access_id) == digital_access_id
+    }
+
+    pub fn apply(&self, price: u64) -> Result<u64> {
+        let discount = match self.kind {
+            DiscountKind::Percentage { bps } => {
+                ((price as u128)
+                    .checked_mul(bps as u128)
+                    .ok_or(DappError::ArithmeticOverflow)?
+                    / MAX_BPS as u128) as u64
+            }
+            DiscountKind::Fixed { amount } => amount,
+        };
+
+        Ok(price.saturating_sub(discount))
+    }
+}
@@ -1,16 +1,111 @@
 use anchor_lang::prelude::*;
 
+use crate::{DappError, EventRole, Role, MAX_BPS, MAX_CREATORS, SETTLEMENT_DISPUTE_WINDOW};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum EventStatus {
+    Active,
+    Cancelled,
+    Settled,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, InitSpace)]
+pub struct EventCreator {
+    pub address: Pubkey,
+    pub share: u8,
+}
+
 #[account]
 #[derive(InitSpace)]
 pub struct Event {
     pub creator: Pubkey,
     pub collection_mint_account: Pubkey,
     pub current_nft_count: u64,
+    // tickets whose Ticket account is still open, they need the event until they are burned
+    pub open_tickets: u64,
     pub current_digital_access_count: u8,
+    pub escrow: bool,
+    pub status: EventStatus,
+    pub paused: bool,
+    pub vault_bump: u8,
     pub bump: u8,
     pub date: i64,
+    pub start_time: i64,
+    pub end_time: i64,
+    pub sales_start: i64,
+    pub sales_end: i64,
+    pub seller_fee_basis_points: u16,
+    pub max_per_wallet: Option<u64>,
+    // bubblegum tree backing the collection when tickets are minted compressed
+    pub merkle_tree: Option<Pubkey>,
+    // collection and tickets are token-2022 mints with metadata and group extensions
+    pub token_2022: bool,
+    #[max_len(MAX_CREATORS)]
+    pub creators: Vec<EventCreator>,
     #[max_len(24)]
     pub name: String,
     #[max_len(80)]
     pub description: String,
 }
+
+impl Event {
+    pub fn is_valid_schedule(
+        start_time: i64,
+        end_time: i64,
+        sales_start: i64,
+        sales_end: i64,
+    ) -> bool {
+        start_time < end_time && sales_start < sales_end && sales_end <= end_time
+    }
+
+    pub fn is_valid_royalty(seller_fee_basis_points: u16, creators: &[EventCreator]) -> bool {
+        seller_fee_basis_points <= MAX_BPS
+            && creators.len() <= MAX_CREATORS
+            && (creators.is_empty()
+                || creators
+                    .iter()
+                    .map(|creator| creator.share as u16)
+                    .sum::<u16>()
+                    == 100)
+    }
+
+    pub fn royalty(&self, amount: u64) -> Result<u64> {
+        let royalty = (amount as u128)
+            .checked_mul(self.seller_fee_basis_points as u128)
+            .ok_or(DappError::ArithmeticOverflow)?
+            / MAX_BPS as u128;
+
+        Ok(royalty as u64)
+    }
+
+    pub fn is_settleable(&self, now: i64) -> bool {
+        now >= self.end_time + SETTLEMENT_DISPUTE_WINDOW
+    }
+
+    pub fn is_on_sale(&self, now: i64) -> bool {
+        self.status == EventStatus::Active && self.sales_start <= now && now < self.sales_end
+    }
+
+    // the creator holds every role, staff need a role account granted for the event
+    pub fn has_role(&self, wallet: &Pubkey, event_role: Option<&EventRole>, role: Role) -> bool {
+        *wallet == self.creator || event_role.is_some_and(|event_role| event_role.has_role(role))
+    }
+
+    pub fn is_ticket_burnable(&self, now: i64) -> bool {
+        match self.status {
+            // escrowed tickets of a cancelled event are burned when claiming the refund
+            EventStatus::Cancelled => !self.escrow,
+            _ => now > self.end_time,
+        }
+    }
+
+    pub fn is_closable(&self, now: i64) -> bool {
+        // escrowed events keep the vault until the proceeds are settled, refunds are
+        // covered by closing only once every ticket is gone
+        match self.status {
+            EventStatus::Active => !self.escrow && now > self.end_time,
+            EventStatus::Cancelled => true,
+            EventStatus::Settled => now > self.end_time,
+        }
+    }
+}
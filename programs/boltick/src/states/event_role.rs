@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum Role {
+    // redeem tickets
+    Scanner,
+    // add and update digital access types and the event metadata
+    Manager,
+    // mint complimentary tickets
+    Minter,
+}
+
+impl Role {
+    fn mask(self) -> u8 {
+        1 << self as u8
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct EventRole {
+    pub event: Pubkey,
+    pub wallet: Pubkey,
+    pub roles: u8,
+    pub bump: u8,
+}
+
+impl EventRole {
+    pub fn has_role(&self, role: Role) -> bool {
+        self.roles & role.mask() != 0
+    }
+
+    pub fn grant(&mut self, role: Role) {
+        self.roles |= role.mask();
+    }
+
+    pub fn revoke(&mut self, role: Role) {
+        self.roles &= !role.mask();
+    }
+}
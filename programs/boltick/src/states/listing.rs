@@ -0,0 +1,11 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct Listing {
+    pub seller: Pubkey,
+    pub event: Pubkey,
+    pub token_mint: Pubkey,
+    pub price: u64,
+    pub bump: u8,
+}
@@ -1,7 +1,23 @@
+pub mod buyer_record;
 pub mod config;
 pub mod digital_access;
+pub mod discount;
 pub mod event;
+pub mod event_role;
+pub mod listing;
+pub mod presale_claim;
+pub mod redemption;
+pub mod ticket;
+pub mod waitlist_entry;
 
+pub use buyer_record::*;
 pub use config::*;
 pub use digital_access::*;
+pub use discount::*;
 pub use event::*;
+pub use event_role::*;
+pub use listing::*;
+pub use presale_claim::*;
+pub use redemption::*;
+pub use ticket::*;
+pub use waitlist_entry::*;
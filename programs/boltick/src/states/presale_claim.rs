@@ -0,0 +1,10 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct PresaleClaim {
+    pub digital_access: Pubkey,
+    pub buyer: Pubkey,
+    pub claimed: u64,
+    pub bump: u8,
+}
@@ -0,0 +1,12 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct Redemption {
+    pub event: Pubkey,
+    pub token_mint: Pubkey,
+    pub redeemed: bool,
+    pub redeemed_by: Pubkey,
+    pub redeemed_at: i64,
+    pub bump: u8,
+}
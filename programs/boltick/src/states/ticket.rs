@@ -0,0 +1,23 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct Ticket {
+    pub event: Pubkey,
+    pub digital_access: Pubkey,
+    pub token_mint: Pubkey,
+    pub price_paid: u64,
+    // price before discounts, auction rebates are computed on it
+    pub base_price: u64,
+    pub payment_mint: Option<Pubkey>,
+    pub bump: u8,
+}
+
+impl Ticket {
+    // the rebate is taken on the price before discounts, so discounted buyers keep their discount
+    pub fn rebate(&self, clearing_price: u64) -> u64 {
+        self.base_price
+            .saturating_sub(clearing_price)
+            .min(self.price_paid)
+    }
+}
@@ -0,0 +1,12 @@
+use anchor_lang::prelude::*;
+
+// holds the escrowed price on top of its rent until the entry is processed or left
+#[account]
+#[derive(InitSpace)]
+pub struct WaitlistEntry {
+    pub digital_access: Pubkey,
+    pub buyer: Pubkey,
+    pub position: u64,
+    pub amount: u64,
+    pub bump: u8,
+}
@@ -1,15 +1,48 @@
 import * as anchor from "@coral-xyz/anchor";
 
 import { ComputeBudgetProgram, LAMPORTS_PER_SOL, Transaction } from "@solana/web3.js";
-import { SEED_COLLECTION_MINT, SEED_CONFIG, SEED_EVENT, SEED_TOKEN_MINT } from "./utils/constants";
+import {
+  ACCOUNT_COMPRESSION_PROGRAM_ID,
+  BUBBLEGUM_PROGRAM_ID,
+  NOOP_PROGRAM_ID,
+  SEED_BUYER_RECORD,
+  SEED_COLLECTION_MINT,
+  SEED_CONFIG,
+  SEED_DIGITAL_ACCESS,
+  SEED_DISCOUNT,
+  SEED_EVENT,
+  SEED_EVENT_ROLE,
+  SEED_LISTING,
+  SEED_PRESALE_CLAIM,
+  SEED_REDEMPTION,
+  SEED_TICKET,
+  SEED_TOKEN_MINT,
+  SEED_TREASURY,
+  SEED_VAULT,
+  SEED_WAITLIST_ENTRY,
+  TOKEN_METADATA_PROGRAM_ID,
+} from "./utils/constants";
+
+import {
+  TOKEN_2022_PROGRAM_ID,
+  createMint,
+  getAccount,
+  getAssociatedTokenAddressSync,
+  getOrCreateAssociatedTokenAccount,
+  getTokenMetadata,
+  mintTo,
+} from "@solana/spl-token";
 
 import { Boltick } from "../target/types/boltick";
 import { Program } from "@coral-xyz/anchor";
 import { TOKEN_PROGRAM_ID } from "@coral-xyz/anchor/dist/cjs/utils/token";
+import { createHash } from "crypto";
 import { expect } from "chai";
+import { keccak_256 } from "@noble/hashes/sha3";
 
 // PARA EL metadata_program_account ES NECESARIO DESCARGAR EL PROGRAMA DE MAINNET Y EJECUTARLO LOCAL
 // https://solana.com/es/developers/cookbook/development/using-mainnet-accounts-programs
+// LO MISMO PARA BUBBLEGUM, ACCOUNT COMPRESSION Y NOOP (tickets comprimidos)
 /*
   #   solana program dump -u <source cluster> <address of account to fetch> <destination file name/path>
   solana program dump -u m PROGRAM_ID NAME.so
@@ -25,6 +58,7 @@ describe("boltick", () => {
   const wallet = provider.wallet;
   const randomKeypair = anchor.web3.Keypair.generate();
   let firstCollectionAddress: string;
+  let splPaymentMint: anchor.web3.PublicKey;
 
   anchor.setProvider(provider);
 
@@ -56,6 +90,71 @@ describe("boltick", () => {
     expect(configAccount.eventCount.toNumber()).to.equal(0);
   });
 
+  it("Should update the protocol fee!", async () => {
+    const protocolFeeBps = 500; // 5%
+
+    const tx = await program.methods.updateProtocolFee(protocolFeeBps).rpc();
+    console.log("Update protocol fee tx signature:", tx);
+
+    const configAccount = await program.account.config.fetch(configPda);
+
+    expect(configAccount.protocolFeeBps).to.equal(protocolFeeBps);
+  });
+
+  it("Should fail to update the protocol fee from a wrong authority!", async () => {
+    try {
+      const tx = await program.methods
+        .updateProtocolFee(1_000)
+        .accounts({ authority: randomKeypair.publicKey })
+        .signers([randomKeypair])
+        .rpc();
+      console.error("Unexpected update protocol fee tx signature:", tx);
+      return expect.fail("Unexpected update protocol fee tx signature:", tx);
+    } catch (err) {
+      console.log("Expected error updating protocol fee:", err?.error?.errorCode?.code);
+      return expect(err.error.errorCode.code).to.equal("InvalidAuthority");
+    }
+  });
+
+  it("Should rotate the Config authority in two steps!", async () => {
+    const proposeTx = await program.methods.proposeAuthority(randomKeypair.publicKey).rpc();
+    console.log("Propose authority tx signature:", proposeTx);
+
+    try {
+      const tx = await program.methods
+        .acceptAuthority()
+        .accounts({ pendingAuthority: wallet.publicKey })
+        .rpc();
+      console.error("Unexpected accept authority tx signature:", tx);
+      return expect.fail("Unexpected accept authority tx signature:", tx);
+    } catch (err) {
+      console.log("Expected error accepting authority:", err?.error?.errorCode?.code);
+      expect(err.error.errorCode.code).to.equal("InvalidPendingAuthority");
+    }
+
+    const acceptTx = await program.methods
+      .acceptAuthority()
+      .accounts({ pendingAuthority: randomKeypair.publicKey })
+      .signers([randomKeypair])
+      .rpc();
+    console.log("Accept authority tx signature:", acceptTx);
+
+    let configAccount = await program.account.config.fetch(configPda);
+    expect(configAccount.authority.toBase58()).to.equal(randomKeypair.publicKey.toBase58());
+    expect(configAccount.pendingAuthority).to.equal(null);
+
+    // hand the authority back for the rest of the suite
+    await program.methods
+      .proposeAuthority(wallet.publicKey)
+      .accounts({ authority: randomKeypair.publicKey })
+      .signers([randomKeypair])
+      .rpc();
+    await program.methods.acceptAuthority().accounts({ pendingAuthority: wallet.publicKey }).rpc();
+
+    configAccount = await program.account.config.fetch(configPda);
+    expect(configAccount.authority.toBase58()).to.equal(wallet.publicKey.toBase58());
+  });
+
   it("Should initialize Event Account!", async () => {
     const name = "Test Event";
     const symbol = "TE";
@@ -63,9 +162,23 @@ describe("boltick", () => {
       "https://raw.githubusercontent.com/franRappazzini/boltick-contracts/main/tests/utils/uri-test.json";
     const eventName = "Test Event";
     const eventId = 0;
+    const now = Math.floor(Date.now() / 1000);
 
     const tx = await program.methods
-      .initializeEvent(name, symbol, uri, eventName)
+      .initializeEvent(
+        name,
+        symbol,
+        uri,
+        eventName,
+        bn(now + DAY), // start time
+        bn(now + 2 * DAY), // end time
+        bn(now - 60), // sales start
+        bn(now + DAY), // sales end
+        false, // escrow
+        0, // seller fee basis points
+        [], // creators
+        null
+      )
       .accounts({ tokenProgram: TOKEN_PROGRAM_ID })
       .rpc({ skipPreflight: true });
     console.log("Initialize Event account tx signature:", tx);
@@ -91,6 +204,35 @@ describe("boltick", () => {
     expect(configAccount.eventCount.toNumber()).to.equal(eventId + 1);
   });
 
+  it("Should fail to initialize an Event with sales ending after the event!", async () => {
+    const now = Math.floor(Date.now() / 1000);
+
+    try {
+      const tx = await program.methods
+        .initializeEvent(
+          "Invalid Event",
+          "IE",
+          "https://raw.githubusercontent.com/franRappazzini/boltick-contracts/main/tests/utils/uri-test.json",
+          "Invalid Event",
+          bn(now + DAY),
+          bn(now + 2 * DAY),
+          bn(now),
+          bn(now + 3 * DAY),
+          false,
+          0,
+          [],
+          null
+        )
+        .accounts({ tokenProgram: TOKEN_PROGRAM_ID })
+        .rpc();
+      console.error("Unexpected initialize event tx signature:", tx);
+      return expect.fail("Unexpected initialize event tx signature:", tx);
+    } catch (err) {
+      console.log("Expected error initializing event:", err?.error?.errorCode?.code);
+      return expect(err.error.errorCode.code).to.equal("InvalidSchedule");
+    }
+  });
+
   it("Should create Digital Access types!", async () => {
     const eventId = 0;
     const price = 0.2 * LAMPORTS_PER_SOL;
@@ -102,7 +244,21 @@ describe("boltick", () => {
       "https://raw.githubusercontent.com/franRappazzini/boltick-contracts/main/tests/utils/uri-test.json";
 
     const tx = await program.methods
-      .addDigitalAccess(bn(eventId), bn(price), bn(max_supply), name, symbol, description, uri)
+      .addDigitalAccess(
+        bn(eventId),
+        bn(price),
+        bn(max_supply),
+        name,
+        symbol,
+        description,
+        uri,
+        null,
+        null,
+        null,
+        true,
+        null
+      )
+      .accounts({ eventRole: null })
       .rpc();
     console.log("Add VIP Digital Access tx signature:", tx);
 
@@ -113,7 +269,21 @@ describe("boltick", () => {
     const description2 = "General Access to the event";
 
     const tx2 = await program.methods
-      .addDigitalAccess(bn(eventId), bn(price2), bn(max_supply2), name2, symbol2, description2, uri)
+      .addDigitalAccess(
+        bn(eventId),
+        bn(price2),
+        bn(max_supply2),
+        name2,
+        symbol2,
+        description2,
+        uri,
+        null,
+        null,
+        null,
+        true,
+        null
+      )
+      .accounts({ eventRole: null })
       .rpc();
     console.log("Add GA Digital Access tx signature:", tx2);
 
@@ -147,6 +317,7 @@ describe("boltick", () => {
     const ix = await program.methods
       .mintToken(bn(eventId), digitalAccessId)
       .accounts({
+        eventRole: null,
         tokenProgram: TOKEN_PROGRAM_ID,
         destination: randomKeypair.publicKey,
       })
@@ -195,7 +366,7 @@ describe("boltick", () => {
     const computeIx = ComputeBudgetProgram.setComputeUnitLimit({ units: 300_000 });
 
     const ix = await program.methods
-      .buyToken(bn(eventId), digitalAccessId)
+      .buyToken(bn(eventId), digitalAccessId, null, null, null, bn(MAX_PRICE))
       .accounts({
         tokenProgram: TOKEN_PROGRAM_ID,
         eventCreator: wallet.publicKey,
@@ -252,7 +423,7 @@ describe("boltick", () => {
     const computeIx = ComputeBudgetProgram.setComputeUnitLimit({ units: 300_000 });
 
     const ix = await program.methods
-      .buyToken(bn(eventId), digitalAccessId)
+      .buyToken(bn(eventId), digitalAccessId, null, null, null, bn(MAX_PRICE))
       .accounts({
         tokenProgram: TOKEN_PROGRAM_ID,
         eventCreator: wallet.publicKey,
@@ -300,7 +471,7 @@ describe("boltick", () => {
 
     try {
       const ix = await program.methods
-        .buyToken(bn(eventId), digitalAccessId)
+        .buyToken(bn(eventId), digitalAccessId, null, null, null, bn(MAX_PRICE))
         .accounts({
           tokenProgram: TOKEN_PROGRAM_ID,
           eventCreator: randomKeypair.publicKey,
@@ -336,6 +507,7 @@ describe("boltick", () => {
       const ix = await program.methods
         .mintToken(bn(eventId), digitalAccessId)
         .accounts({
+          eventRole: null,
           tokenProgram: TOKEN_PROGRAM_ID,
           destination: randomKeypair.publicKey,
         })
@@ -355,24 +527,2383 @@ describe("boltick", () => {
     }
   });
 
-  it("Should update token metadata!", async () => {
+  it("Should buy a token paying with an SPL token!", async () => {
     const eventId = 0;
-    const nftId = 0;
+    const digitalAccessId = 2;
+    const price = 10_000_000; // 10 tokens with 6 decimals
+    const uri =
+      "https://raw.githubusercontent.com/franRappazzini/boltick-contracts/main/tests/utils/uri-test.json";
+
+    const [treasuryPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from(SEED_TREASURY)],
+      program.programId
+    );
+
+    const paymentMint = await createMint(connection, wallet.payer, wallet.publicKey, null, 6);
+    splPaymentMint = paymentMint;
+    const buyerAta = await getOrCreateAssociatedTokenAccount(
+      connection,
+      wallet.payer,
+      paymentMint,
+      randomKeypair.publicKey
+    );
+    const creatorAta = await getOrCreateAssociatedTokenAccount(
+      connection,
+      wallet.payer,
+      paymentMint,
+      wallet.publicKey
+    );
+    const treasuryAta = await getOrCreateAssociatedTokenAccount(
+      connection,
+      wallet.payer,
+      paymentMint,
+      treasuryPda,
+      true
+    );
+    await mintTo(connection, wallet.payer, paymentMint, buyerAta.address, wallet.payer, price);
+
+    const tx = await program.methods
+      .addDigitalAccess(
+        bn(eventId),
+        bn(price),
+        bn(10),
+        "USDC Access",
+        "USDC",
+        "Paid in USDC",
+        uri,
+        null,
+        null,
+        null,
+        true,
+        null
+      )
+      .accounts({ eventRole: null, paymentMint })
+      .rpc();
+    console.log("Add USDC Digital Access tx signature:", tx);
+
+    const computeIx = ComputeBudgetProgram.setComputeUnitLimit({ units: 400_000 });
+
+    const ix = await program.methods
+      .buyToken(bn(eventId), digitalAccessId, null, null, null, bn(MAX_PRICE))
+      .accounts({
+        tokenProgram: TOKEN_PROGRAM_ID,
+        eventCreator: wallet.publicKey,
+        buyer: randomKeypair.publicKey,
+        paymentMint,
+        buyerPaymentTokenAccount: buyerAta.address,
+        creatorPaymentTokenAccount: creatorAta.address,
+        treasuryPaymentTokenAccount: treasuryAta.address,
+        paymentTokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .instruction();
+
+    const buyTx = new Transaction().add(computeIx, ix);
+
+    buyTx.feePayer = randomKeypair.publicKey;
+    buyTx.recentBlockhash = (await provider.connection.getLatestBlockhash()).blockhash;
+
+    const signature = await anchor.web3.sendAndConfirmTransaction(
+      connection,
+      buyTx,
+      [randomKeypair],
+      { skipPreflight: true }
+    );
+    console.log("Buy token with SPL tx signature:", signature);
+
+    const configAccount = await program.account.config.fetch(configPda);
+    const fee = (price * configAccount.protocolFeeBps) / 10_000;
 
+    expect(Number((await getAccount(connection, buyerAta.address)).amount)).to.equal(0);
+    expect(Number((await getAccount(connection, treasuryAta.address)).amount)).to.equal(fee);
+    expect(Number((await getAccount(connection, creatorAta.address)).amount)).to.equal(
+      price - fee
+    );
+  });
+
+  it("Should fail to buy a token from a closed early bird Digital Access!", async () => {
+    const eventId = 0;
+    const digitalAccessId = 3;
+    const now = Math.floor(Date.now() / 1000);
     const uri =
-      "https://raw.githubusercontent.com/franRappazzini/boltick-contracts/main/tests/utils/uri-test-update.json";
+      "https://raw.githubusercontent.com/franRappazzini/boltick-contracts/main/tests/utils/uri-test.json";
 
-    // 10' to wait for the transaction to check old metadata in explorer
-    setTimeout(async () => {
+    const tx = await program.methods
+      .addDigitalAccess(
+        bn(eventId),
+        bn(0.05 * LAMPORTS_PER_SOL),
+        bn(10),
+        "Early Bird",
+        "EB",
+        "Early Bird Access",
+        uri,
+        bn(now - 120),
+        bn(now - 60),
+        null,
+        true,
+        null
+      )
+      .accounts({ eventRole: null })
+      .rpc();
+    console.log("Add Early Bird Digital Access tx signature:", tx);
+
+    try {
+      const computeIx = ComputeBudgetProgram.setComputeUnitLimit({ units: 300_000 });
+      const ix = await program.methods
+        .buyToken(bn(eventId), digitalAccessId, null, null, null, bn(MAX_PRICE))
+        .accounts({ tokenProgram: TOKEN_PROGRAM_ID, eventCreator: wallet.publicKey })
+        .instruction();
+
+      const signature = await provider.sendAndConfirm(new Transaction().add(computeIx, ix));
+      console.error("Unexpected buy token tx signature:", signature);
+      return expect.fail("Unexpected buy token tx signature:", signature);
+    } catch (err) {
+      console.log("Expected error buying token:", err?.logs?.slice(-3));
+      return expect(err.logs.join(" ")).to.include("SaleNotActive");
+    }
+  });
+
+  it("Should withdraw protocol fees from the treasury!", async () => {
+    const [treasuryPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from(SEED_TREASURY)],
+      program.programId
+    );
+    const destination = anchor.web3.Keypair.generate().publicKey;
+
+    const treasuryBalance = await connection.getBalance(treasuryPda);
+    const rentExempt = await connection.getMinimumBalanceForRentExemption(0);
+    const amount = treasuryBalance - rentExempt;
+
+    const tx = await program.methods.withdrawTreasury(bn(amount)).accounts({ destination }).rpc();
+    console.log("Withdraw treasury tx signature:", tx);
+
+    expect(amount).to.be.greaterThan(0);
+    expect(await connection.getBalance(destination)).to.equal(amount);
+    expect(await connection.getBalance(treasuryPda)).to.equal(rentExempt);
+  });
+
+  it("Should withdraw SPL protocol fees from the treasury!", async () => {
+    const [treasuryPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from(SEED_TREASURY)],
+      program.programId
+    );
+    const destination = anchor.web3.Keypair.generate().publicKey;
+    const destinationAta = await getOrCreateAssociatedTokenAccount(
+      connection,
+      wallet.payer,
+      splPaymentMint,
+      destination
+    );
+    const treasuryAta = getAssociatedTokenAddressSync(splPaymentMint, treasuryPda, true);
+    const amount = Number((await getAccount(connection, treasuryAta)).amount);
+
+    try {
       const tx = await program.methods
-        .updateTokenMetadata(bn(eventId), bn(nftId), uri)
-        // .accounts({})
-        .rpc({ skipPreflight: true });
+        .withdrawTreasury(bn(amount))
+        .accounts({
+          destination,
+          paymentMint: splPaymentMint,
+          destinationPaymentTokenAccount: null,
+          paymentTokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .rpc();
+      console.error("Unexpected withdraw treasury tx signature:", tx);
+      return expect.fail("Unexpected withdraw treasury tx signature:", tx);
+    } catch (err) {
+      console.log("Expected error withdrawing without a destination:", err?.error?.errorCode?.code);
+      expect(err.error.errorCode.code).to.equal("MissingPaymentAccounts");
+    }
 
-      console.log("Update token metadata tx signature:", tx);
-    }, 10000);
+    const tx = await program.methods
+      .withdrawTreasury(bn(amount))
+      .accounts({
+        destination,
+        paymentMint: splPaymentMint,
+        paymentTokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .rpc();
+    console.log("Withdraw SPL treasury tx signature:", tx);
+
+    expect(amount).to.be.greaterThan(0);
+    expect(Number((await getAccount(connection, destinationAta.address)).amount)).to.equal(amount);
+    expect(Number((await getAccount(connection, treasuryAta)).amount)).to.equal(0);
   });
-});
+
+  it("Should grant the Scanner role and redeem a token with it!", async () => {
+    const eventId = 0;
+    const nftId = 1; // bought by randomKeypair
+
+    const tx = await program.methods
+      .grantRole(bn(eventId), randomKeypair.publicKey, { scanner: {} })
+      .rpc();
+    console.log("Grant role tx signature:", tx);
+
+    const [eventPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from(SEED_EVENT), bn(eventId).toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+    const [eventRolePda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from(SEED_EVENT_ROLE), eventPda.toBuffer(), randomKeypair.publicKey.toBuffer()],
+      program.programId
+    );
+
+    const tx2 = await program.methods
+      .redeemToken(bn(eventId), bn(nftId))
+      .accounts({ signer: randomKeypair.publicKey, eventRole: eventRolePda })
+      .signers([randomKeypair])
+      .rpc();
+    console.log("Redeem token tx signature:", tx2);
+
+    const [nftPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from(SEED_TOKEN_MINT),
+        new anchor.web3.PublicKey(firstCollectionAddress).toBuffer(),
+        bn(nftId).toArrayLike(Buffer, "le", 8),
+      ],
+      program.programId
+    );
+    const [redemptionPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from(SEED_REDEMPTION), nftPda.toBuffer()],
+      program.programId
+    );
+    const redemptionAccount = await program.account.redemption.fetch(redemptionPda);
+
+    expect(redemptionAccount.redeemed).to.equal(true);
+    expect(redemptionAccount.redeemedBy.toBase58()).to.equal(randomKeypair.publicKey.toBase58());
+
+    // revoking the only role closes the account
+    await program.methods.revokeRole(bn(eventId), randomKeypair.publicKey, { scanner: {} }).rpc();
+    expect(await connection.getAccountInfo(eventRolePda)).to.be.null;
+  });
+
+  it("Should fail to redeem a token twice!", async () => {
+    const eventId = 0;
+    const nftId = 1;
+
+    try {
+      const tx = await program.methods
+        .redeemToken(bn(eventId), bn(nftId))
+        .accounts({ eventRole: null })
+        .rpc();
+      console.error("Unexpected redeem token tx signature:", tx);
+      return expect.fail("Unexpected redeem token tx signature:", tx);
+    } catch (err) {
+      console.log("Expected error redeeming token:", err?.error?.errorCode?.code);
+      return expect(err.error.errorCode.code).to.equal("TicketAlreadyRedeemed");
+    }
+  });
+
+  it("Should fail to list a redeemed ticket or to buy one redeemed while listed!", async () => {
+    const eventId = 0;
+    const redeemedNftId = 1; // bought by randomKeypair and redeemed
+    const listedNftId = 2; // bought by the authority account
+    const price = 0.1 * LAMPORTS_PER_SOL;
+
+    try {
+      const tx = await program.methods
+        .listTicket(bn(eventId), bn(redeemedNftId), bn(price))
+        .accounts({ seller: randomKeypair.publicKey, tokenProgram: TOKEN_PROGRAM_ID })
+        .signers([randomKeypair])
+        .rpc();
+      console.error("Unexpected list ticket tx signature:", tx);
+      return expect.fail("Unexpected list ticket tx signature:", tx);
+    } catch (err) {
+      console.log("Expected error listing a redeemed ticket:", err?.error?.errorCode?.code);
+      expect(err.error.errorCode.code).to.equal("TicketAlreadyRedeemed");
+    }
+
+    const [nftPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from(SEED_TOKEN_MINT),
+        new anchor.web3.PublicKey(firstCollectionAddress).toBuffer(),
+        bn(listedNftId).toArrayLike(Buffer, "le", 8),
+      ],
+      program.programId
+    );
+
+    await program.methods
+      .listTicket(bn(eventId), bn(listedNftId), bn(price))
+      .accounts({ tokenProgram: TOKEN_PROGRAM_ID })
+      .rpc();
+
+    // staff can still check in a ticket sitting in the listing escrow
+    await program.methods
+      .redeemToken(bn(eventId), bn(listedNftId))
+      .accounts({ eventRole: null })
+      .rpc();
+
+    try {
+      const tx = await program.methods
+        .buyListing()
+        .accounts({
+          buyer: randomKeypair.publicKey,
+          tokenMint: nftPda,
+          eventCreator: wallet.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([randomKeypair])
+        .rpc();
+      console.error("Unexpected buy listing tx signature:", tx);
+      return expect.fail("Unexpected buy listing tx signature:", tx);
+    } catch (err) {
+      console.log("Expected error buying a redeemed ticket:", err?.error?.errorCode?.code);
+      expect(err.error.errorCode.code).to.equal("TicketAlreadyRedeemed");
+    }
+
+    await program.methods
+      .cancelListing()
+      .accounts({ tokenMint: nftPda, tokenProgram: TOKEN_PROGRAM_ID })
+      .rpc();
+  });
+
+  it("Should refund an escrowed ticket after the event is cancelled!", async () => {
+    const eventId = 1;
+    const digitalAccessId = 0;
+    const nftId = 0;
+    const price = 0.1 * LAMPORTS_PER_SOL;
+    const now = Math.floor(Date.now() / 1000);
+    const uri =
+      "https://raw.githubusercontent.com/franRappazzini/boltick-contracts/main/tests/utils/uri-test.json";
+
+    await program.methods
+      .initializeEvent(
+        "Escrow Event",
+        "EE",
+        uri,
+        "Escrow Event",
+        bn(now + DAY),
+        bn(now + 2 * DAY),
+        bn(now - 60),
+        bn(now + DAY),
+        true,
+        0,
+        [],
+        null
+      )
+      .accounts({ tokenProgram: TOKEN_PROGRAM_ID })
+      .rpc();
+
+    await program.methods
+      .addDigitalAccess(
+        bn(eventId),
+        bn(price),
+        bn(10),
+        "General",
+        "GA",
+        "General",
+        uri,
+        null,
+        null,
+        null,
+        true,
+        null
+      )
+      .accounts({ eventRole: null })
+      .rpc();
+
+    const [eventPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from(SEED_EVENT), bn(eventId).toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+    const [vaultPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from(SEED_VAULT), eventPda.toBuffer()],
+      program.programId
+    );
+    const vaultPrevBalance = await connection.getBalance(vaultPda);
+
+    const computeIx = ComputeBudgetProgram.setComputeUnitLimit({ units: 300_000 });
+    const buyIx = await program.methods
+      .buyToken(bn(eventId), digitalAccessId, null, null, null, bn(MAX_PRICE))
+      .accounts({
+        tokenProgram: TOKEN_PROGRAM_ID,
+        eventCreator: wallet.publicKey,
+        buyer: randomKeypair.publicKey,
+      })
+      .instruction();
+
+    const buyTx = new Transaction().add(computeIx, buyIx);
+    buyTx.feePayer = randomKeypair.publicKey;
+    buyTx.recentBlockhash = (await provider.connection.getLatestBlockhash()).blockhash;
+
+    const buySignature = await anchor.web3.sendAndConfirmTransaction(
+      connection,
+      buyTx,
+      [randomKeypair],
+      { skipPreflight: true }
+    );
+    console.log("Buy escrowed token tx signature:", buySignature);
+
+    expect(await connection.getBalance(vaultPda)).to.equal(vaultPrevBalance + price);
+
+    const cancelTx = await program.methods.cancelEvent(bn(eventId)).rpc();
+    console.log("Cancel event tx signature:", cancelTx);
+
+    const refundTx = await program.methods
+      .claimRefund(bn(eventId), bn(nftId))
+      .accounts({ holder: randomKeypair.publicKey, tokenProgram: TOKEN_PROGRAM_ID })
+      .signers([randomKeypair])
+      .rpc();
+    console.log("Claim refund tx signature:", refundTx);
+
+    const eventAccount = await program.account.event.fetch(eventPda);
+
+    expect(eventAccount.status).to.deep.equal({ cancelled: {} });
+    expect(await connection.getBalance(vaultPda)).to.equal(vaultPrevBalance);
+  });
+
+  it("Should settle escrowed proceeds early with the Config authority!", async () => {
+    const eventId = 2;
+    const digitalAccessId = 0;
+    const price = 0.1 * LAMPORTS_PER_SOL;
+    const now = Math.floor(Date.now() / 1000);
+    const uri =
+      "https://raw.githubusercontent.com/franRappazzini/boltick-contracts/main/tests/utils/uri-test.json";
+
+    await program.methods
+      .initializeEvent(
+        "Settled Event",
+        "SE",
+        uri,
+        "Settled Event",
+        bn(now + DAY),
+        bn(now + 2 * DAY),
+        bn(now - 60),
+        bn(now + DAY),
+        true,
+        0,
+        [],
+        null
+      )
+      .accounts({ tokenProgram: TOKEN_PROGRAM_ID })
+      .rpc();
+
+    await program.methods
+      .addDigitalAccess(
+        bn(eventId),
+        bn(price),
+        bn(10),
+        "General",
+        "GA",
+        "General",
+        uri,
+        null,
+        null,
+        null,
+        true,
+        null
+      )
+      .accounts({ eventRole: null })
+      .rpc();
+
+    const computeIx = ComputeBudgetProgram.setComputeUnitLimit({ units: 300_000 });
+    const buyIx = await program.methods
+      .buyToken(bn(eventId), digitalAccessId, null, null, null, bn(MAX_PRICE))
+      .accounts({
+        tokenProgram: TOKEN_PROGRAM_ID,
+        eventCreator: wallet.publicKey,
+        buyer: randomKeypair.publicKey,
+      })
+      .instruction();
+
+    const buyTx = new Transaction().add(computeIx, buyIx);
+    buyTx.feePayer = randomKeypair.publicKey;
+    buyTx.recentBlockhash = (await provider.connection.getLatestBlockhash()).blockhash;
+
+    await anchor.web3.sendAndConfirmTransaction(connection, buyTx, [randomKeypair], {
+      skipPreflight: true,
+    });
+
+    const [eventPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from(SEED_EVENT), bn(eventId).toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+    const [vaultPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from(SEED_VAULT), eventPda.toBuffer()],
+      program.programId
+    );
+    const [digitalAccessPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from(SEED_DIGITAL_ACCESS), eventPda.toBuffer(), Buffer.from([digitalAccessId])],
+      program.programId
+    );
+    const digitalAccesses = [{ pubkey: digitalAccessPda, isSigner: false, isWritable: false }];
+    const [treasuryPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from(SEED_TREASURY)],
+      program.programId
+    );
+    const treasuryPrevBalance = await connection.getBalance(treasuryPda);
+
+    try {
+      const tx = await program.methods
+        .settleEvent(bn(eventId))
+        .accounts({ signer: randomKeypair.publicKey, eventCreator: wallet.publicKey })
+        .remainingAccounts(digitalAccesses)
+        .signers([randomKeypair])
+        .rpc();
+      console.error("Unexpected settle event tx signature:", tx);
+      return expect.fail("Unexpected settle event tx signature:", tx);
+    } catch (err) {
+      console.log("Expected error settling from a wrong signer:", err?.error?.errorCode?.code);
+      expect(err.error.errorCode.code).to.equal("InvalidAuthority");
+    }
+
+    // the config authority does not wait for the event to end
+    const tx = await program.methods
+      .settleEvent(bn(eventId))
+      .accounts({ eventCreator: wallet.publicKey })
+      .remainingAccounts(digitalAccesses)
+      .rpc();
+    console.log("Settle event tx signature:", tx);
+
+    const configAccount = await program.account.config.fetch(configPda);
+    const eventAccount = await program.account.event.fetch(eventPda);
+    const fee = (price * configAccount.protocolFeeBps) / 10_000;
+
+    expect(eventAccount.status).to.deep.equal({ settled: {} });
+    expect(await connection.getBalance(vaultPda)).to.equal(
+      await connection.getMinimumBalanceForRentExemption(0)
+    );
+    expect(await connection.getBalance(treasuryPda)).to.equal(treasuryPrevBalance + fee);
+  });
+
+  it("Should initialize an Event with royalties split between creators!", async () => {
+    const eventId = 3;
+    const sellerFeeBasisPoints = 500; // 5%
+    const creators = [
+      { address: wallet.publicKey, share: 90 },
+      { address: randomKeypair.publicKey, share: 10 },
+    ];
+    const now = Math.floor(Date.now() / 1000);
+    const uri =
+      "https://raw.githubusercontent.com/franRappazzini/boltick-contracts/main/tests/utils/uri-test.json";
+
+    const tx = await program.methods
+      .initializeEvent(
+        "Royalty Event",
+        "RE",
+        uri,
+        "Royalty Event",
+        bn(now + DAY),
+        bn(now + 2 * DAY),
+        bn(now - 60),
+        bn(now + DAY),
+        false,
+        sellerFeeBasisPoints,
+        creators,
+        null
+      )
+      .accounts({ tokenProgram: TOKEN_PROGRAM_ID })
+      .rpc();
+    console.log("Initialize Royalty Event tx signature:", tx);
+
+    const [eventPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from(SEED_EVENT), bn(eventId).toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+    const eventAccount = await program.account.event.fetch(eventPda);
+
+    expect(eventAccount.sellerFeeBasisPoints).to.equal(sellerFeeBasisPoints);
+    expect(eventAccount.creators.map((c) => c.share)).to.deep.equal([90, 10]);
+  });
+
+  it("Should fail to initialize an Event with creator shares not adding up to 100!", async () => {
+    const now = Math.floor(Date.now() / 1000);
+
+    try {
+      const tx = await program.methods
+        .initializeEvent(
+          "Invalid Royalty",
+          "IR",
+          "https://raw.githubusercontent.com/franRappazzini/boltick-contracts/main/tests/utils/uri-test.json",
+          "Invalid Royalty",
+          bn(now + DAY),
+          bn(now + 2 * DAY),
+          bn(now - 60),
+          bn(now + DAY),
+          false,
+          500,
+          [{ address: wallet.publicKey, share: 90 }],
+          null
+        )
+        .accounts({ tokenProgram: TOKEN_PROGRAM_ID })
+        .rpc();
+      console.error("Unexpected initialize event tx signature:", tx);
+      return expect.fail("Unexpected initialize event tx signature:", tx);
+    } catch (err) {
+      console.log("Expected error initializing event:", err?.error?.errorCode?.code);
+      return expect(err.error.errorCode.code).to.equal("InvalidCreators");
+    }
+  });
+
+  it("Should fail to list a ticket above the resale price cap!", async () => {
+    const eventId = 3;
+    const digitalAccessId = 0;
+    const nftId = 0;
+    const price = 0.1 * LAMPORTS_PER_SOL;
+    const uri =
+      "https://raw.githubusercontent.com/franRappazzini/boltick-contracts/main/tests/utils/uri-test.json";
+
+    await program.methods
+      .addDigitalAccess(
+        bn(eventId),
+        bn(price),
+        bn(10),
+        "Capped Resale",
+        "CR",
+        "Resale capped at 10% over face value",
+        uri,
+        null,
+        null,
+        1_000, // 10%
+        true,
+        null
+      )
+      .accounts({ eventRole: null })
+      .rpc();
+
+    const computeIx = ComputeBudgetProgram.setComputeUnitLimit({ units: 300_000 });
+    const mintIx = await program.methods
+      .mintToken(bn(eventId), digitalAccessId)
+      .accounts({ eventRole: null, tokenProgram: TOKEN_PROGRAM_ID, destination: wallet.publicKey })
+      .instruction();
+    await provider.sendAndConfirm(new Transaction().add(computeIx, mintIx));
+
+    try {
+      const tx = await program.methods
+        .listTicket(bn(eventId), bn(nftId), bn(2 * price))
+        .accounts({ tokenProgram: TOKEN_PROGRAM_ID })
+        .rpc();
+      console.error("Unexpected list ticket tx signature:", tx);
+      return expect.fail("Unexpected list ticket tx signature:", tx);
+    } catch (err) {
+      console.log("Expected error listing ticket:", err?.error?.errorCode?.code);
+      return expect(err.error.errorCode.code).to.equal("ResalePriceTooHigh");
+    }
+  });
+
+  it("Should list a ticket and buy it paying royalties to the creators!", async () => {
+    const eventId = 3;
+    const nftId = 0;
+    const price = 0.11 * LAMPORTS_PER_SOL;
+
+    const [eventPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from(SEED_EVENT), bn(eventId).toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+    const eventAccount = await program.account.event.fetch(eventPda);
+    const [nftPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from(SEED_TOKEN_MINT),
+        eventAccount.collectionMintAccount.toBuffer(),
+        bn(nftId).toArrayLike(Buffer, "le", 8),
+      ],
+      program.programId
+    );
+    const [listingPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from(SEED_LISTING), nftPda.toBuffer()],
+      program.programId
+    );
+
+    const listTx = await program.methods
+      .listTicket(bn(eventId), bn(nftId), bn(price))
+      .accounts({ tokenProgram: TOKEN_PROGRAM_ID })
+      .rpc();
+    console.log("List ticket tx signature:", listTx);
+
+    const listingAccount = await program.account.listing.fetch(listingPda);
+    expect(listingAccount.price.toNumber()).to.equal(price);
+
+    const buyTx = await program.methods
+      .buyListing()
+      .accounts({
+        buyer: randomKeypair.publicKey,
+        tokenMint: nftPda,
+        eventCreator: wallet.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .remainingAccounts(
+        eventAccount.creators.map((creator) => ({
+          pubkey: creator.address,
+          isSigner: false,
+          isWritable: true,
+        }))
+      )
+      .signers([randomKeypair])
+      .rpc();
+    console.log("Buy listing tx signature:", buyTx);
+
+    const buyerAta = await getOrCreateAssociatedTokenAccount(
+      connection,
+      wallet.payer,
+      nftPda,
+      randomKeypair.publicKey
+    );
+
+    expect(Number(buyerAta.amount)).to.equal(1);
+    expect(await connection.getAccountInfo(listingPda)).to.equal(null);
+  });
+
+  it("Should mint a soulbound ticket frozen in the holder wallet!", async () => {
+    const eventId = 3;
+    const digitalAccessId = 1;
+    const nftId = 1;
+    const uri =
+      "https://raw.githubusercontent.com/franRappazzini/boltick-contracts/main/tests/utils/uri-test.json";
+
+    await program.methods
+      .addDigitalAccess(
+        bn(eventId),
+        bn(0.1 * LAMPORTS_PER_SOL),
+        bn(10),
+        "Soulbound",
+        "SB",
+        "Non-transferable access",
+        uri,
+        null,
+        null,
+        null,
+        false,
+        null
+      )
+      .accounts({ eventRole: null })
+      .rpc();
+
+    const computeIx = ComputeBudgetProgram.setComputeUnitLimit({ units: 300_000 });
+
+    try {
+      const ix = await program.methods
+        .mintToken(bn(eventId), digitalAccessId)
+        .accounts({
+          eventRole: null,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          destination: randomKeypair.publicKey,
+        })
+        .instruction();
+      const tx = await provider.sendAndConfirm(new Transaction().add(computeIx, ix));
+      console.error("Unexpected mint token tx signature:", tx);
+      return expect.fail("Unexpected mint token tx signature:", tx);
+    } catch (err) {
+      console.log("Expected error minting soulbound token:", err?.logs?.slice(-3));
+      expect(err.logs.join(" ")).to.include("DestinationMustSign");
+    }
+
+    const mintIx = await program.methods
+      .mintToken(bn(eventId), digitalAccessId)
+      .accounts({ eventRole: null, tokenProgram: TOKEN_PROGRAM_ID, destination: wallet.publicKey })
+      .instruction();
+    await provider.sendAndConfirm(new Transaction().add(computeIx, mintIx));
+
+    const [eventPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from(SEED_EVENT), bn(eventId).toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+    const eventAccount = await program.account.event.fetch(eventPda);
+    const [nftPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from(SEED_TOKEN_MINT),
+        eventAccount.collectionMintAccount.toBuffer(),
+        bn(nftId).toArrayLike(Buffer, "le", 8),
+      ],
+      program.programId
+    );
+    const ata = getAssociatedTokenAddressSync(nftPda, wallet.publicKey);
+    expect((await getAccount(connection, ata)).isFrozen).to.equal(true);
+
+    const thawTx = await program.methods
+      .thawTicket(bn(eventId), bn(nftId))
+      .accounts({ tokenAccount: ata, tokenProgram: TOKEN_PROGRAM_ID })
+      .rpc();
+    console.log("Thaw ticket tx signature:", thawTx);
+    expect((await getAccount(connection, ata)).isFrozen).to.equal(false);
+
+    const freezeTx = await program.methods
+      .freezeTicket(bn(eventId), bn(nftId))
+      .accounts({ holder: wallet.publicKey, tokenProgram: TOKEN_PROGRAM_ID })
+      .rpc();
+    console.log("Freeze ticket tx signature:", freezeTx);
+    expect((await getAccount(connection, ata)).isFrozen).to.equal(true);
+  });
+
+  it("Should fail to lower a Digital Access max supply below the minted tokens!", async () => {
+    const eventId = 3;
+    const digitalAccessId = 0;
+
+    try {
+      const tx = await program.methods
+        .updateDigitalAccess(bn(eventId), digitalAccessId, null, bn(0), null, null, null)
+        .accounts({ eventRole: null })
+        .rpc();
+      console.error("Unexpected update digital access tx signature:", tx);
+      return expect.fail("Unexpected update digital access tx signature:", tx);
+    } catch (err) {
+      console.log("Expected error updating digital access:", err?.error?.errorCode?.code);
+      return expect(err.error.errorCode.code).to.equal("InvalidMaxSupply");
+    }
+  });
+
+  it("Should update a Digital Access and close it!", async () => {
+    const eventId = 3;
+    const digitalAccessId = 0;
+    const price = 0.2 * LAMPORTS_PER_SOL;
+    const uri =
+      "https://raw.githubusercontent.com/franRappazzini/boltick-contracts/main/tests/utils/uri-test-update.json";
+
+    const [eventPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from(SEED_EVENT), bn(eventId).toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+    const [digitalAccessPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from(SEED_DIGITAL_ACCESS), eventPda.toBuffer(), Buffer.from([digitalAccessId])],
+      program.programId
+    );
+
+    const updateTx = await program.methods
+      .updateDigitalAccess(bn(eventId), digitalAccessId, bn(price), bn(20), null, null, uri)
+      .accounts({ eventRole: null })
+      .rpc();
+    console.log("Update digital access tx signature:", updateTx);
+
+    let digitalAccessAccount = await program.account.digitalAccess.fetch(digitalAccessPda);
+    expect(digitalAccessAccount.price.toNumber()).to.equal(price);
+    expect(digitalAccessAccount.maxSupply.toNumber()).to.equal(20);
+    expect(digitalAccessAccount.uri).to.equal(uri);
+
+    const closeTx = await program.methods
+      .closeDigitalAccess(bn(eventId), digitalAccessId)
+      .accounts({ eventRole: null })
+      .rpc();
+    console.log("Close digital access tx signature:", closeTx);
+
+    digitalAccessAccount = await program.account.digitalAccess.fetch(digitalAccessPda);
+    expect(digitalAccessAccount.closed).to.equal(true);
+
+    try {
+      const computeIx = ComputeBudgetProgram.setComputeUnitLimit({ units: 300_000 });
+      const ix = await program.methods
+        .buyToken(bn(eventId), digitalAccessId, null, null, null, bn(MAX_PRICE))
+        .accounts({ tokenProgram: TOKEN_PROGRAM_ID, eventCreator: wallet.publicKey })
+        .instruction();
+
+      const signature = await provider.sendAndConfirm(new Transaction().add(computeIx, ix));
+      console.error("Unexpected buy token tx signature:", signature);
+      return expect.fail("Unexpected buy token tx signature:", signature);
+    } catch (err) {
+      console.log("Expected error buying token:", err?.logs?.slice(-3));
+      return expect(err.logs.join(" ")).to.include("SaleNotActive");
+    }
+  });
+
+  it("Should update an Event and its collection metadata!", async () => {
+    const eventId = 3;
+    const uri =
+      "https://raw.githubusercontent.com/franRappazzini/boltick-contracts/main/tests/utils/uri-test-update.json";
+
+    const tx = await program.methods
+      .updateEvent(bn(eventId), "Royalty Event II", null, uri, "Second edition")
+      .accounts({ eventRole: null })
+      .rpc();
+    console.log("Update event tx signature:", tx);
+
+    const [eventPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from(SEED_EVENT), bn(eventId).toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+    const eventAccount = await program.account.event.fetch(eventPda);
+
+    expect(eventAccount.name).to.equal("Royalty Event II");
+    expect(eventAccount.description).to.equal("Second edition");
+  });
+
+  it("Should fail to buy a token while the program is paused!", async () => {
+    const eventId = 0;
+    const digitalAccessId = 1;
+
+    const pauseTx = await program.methods.setPaused(true).rpc();
+    console.log("Pause program tx signature:", pauseTx);
+
+    try {
+      const tx = await program.methods
+        .buyToken(bn(eventId), digitalAccessId, null, null, null, bn(MAX_PRICE))
+        .accounts({ tokenProgram: TOKEN_PROGRAM_ID, eventCreator: wallet.publicKey })
+        .rpc();
+      console.error("Unexpected buy token tx signature:", tx);
+      return expect.fail("Unexpected buy token tx signature:", tx);
+    } catch (err) {
+      console.log("Expected error buying token:", err?.error?.errorCode?.code);
+      expect(err.error.errorCode.code).to.equal("ProgramPaused");
+    } finally {
+      await program.methods.setPaused(false).rpc();
+    }
+  });
+
+  it("Should fail to add a Digital Access while the event is paused!", async () => {
+    const eventId = 3;
+    const uri =
+      "https://raw.githubusercontent.com/franRappazzini/boltick-contracts/main/tests/utils/uri-test.json";
+
+    const pauseTx = await program.methods.setEventPaused(bn(eventId), true).rpc();
+    console.log("Pause event tx signature:", pauseTx);
+
+    try {
+      const tx = await program.methods
+        .addDigitalAccess(
+          bn(eventId),
+          bn(0.1 * LAMPORTS_PER_SOL),
+          bn(10),
+          "Paused",
+          "PA",
+          "Paused Access",
+          uri,
+          null,
+          null,
+          null,
+          true,
+          null
+        )
+        .accounts({ eventRole: null })
+        .rpc();
+      console.error("Unexpected add digital access tx signature:", tx);
+      return expect.fail("Unexpected add digital access tx signature:", tx);
+    } catch (err) {
+      console.log("Expected error adding digital access:", err?.error?.errorCode?.code);
+      expect(err.error.errorCode.code).to.equal("EventPaused");
+    } finally {
+      await program.methods.setEventPaused(bn(eventId), false).rpc();
+    }
+  });
+
+  it("Should buy a token during the presale with a Merkle proof only once!", async () => {
+    const eventId = 3;
+    const digitalAccessId = 2;
+    const now = Math.floor(Date.now() / 1000);
+    const uri =
+      "https://raw.githubusercontent.com/franRappazzini/boltick-contracts/main/tests/utils/uri-test.json";
+
+    await program.methods
+      .addDigitalAccess(
+        bn(eventId),
+        bn(0.05 * LAMPORTS_PER_SOL),
+        bn(10),
+        "Members",
+        "MB",
+        "Members presale",
+        uri,
+        bn(now + DAY),
+        null,
+        null,
+        true,
+        null
+      )
+      .accounts({ eventRole: null })
+      .rpc();
+
+    // leaves are sha256(buyer) or sha256(buyer, allocation)
+    const randomLeaf = sha256(randomKeypair.publicKey.toBuffer());
+    const walletLeaf = sha256(wallet.publicKey.toBuffer(), bn(2).toArrayLike(Buffer, "le", 8));
+    const root = sha256(...[randomLeaf, walletLeaf].sort(Buffer.compare));
+
+    const presaleTx = await program.methods
+      .setPresale(bn(eventId), digitalAccessId, [...root], bn(now - 60), bn(now + DAY))
+      .accounts({ eventRole: null })
+      .rpc();
+    console.log("Set presale tx signature:", presaleTx);
+
+    const [eventPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from(SEED_EVENT), bn(eventId).toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+    const [digitalAccessPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from(SEED_DIGITAL_ACCESS), eventPda.toBuffer(), Buffer.from([digitalAccessId])],
+      program.programId
+    );
+    const [presaleClaimPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from(SEED_PRESALE_CLAIM),
+        digitalAccessPda.toBuffer(),
+        randomKeypair.publicKey.toBuffer(),
+      ],
+      program.programId
+    );
+
+    const computeIx = ComputeBudgetProgram.setComputeUnitLimit({ units: 300_000 });
+    const buy = async () => {
+      const ix = await program.methods
+        .buyToken(bn(eventId), digitalAccessId, [[...walletLeaf]], null, null, bn(MAX_PRICE))
+        .accounts({
+          tokenProgram: TOKEN_PROGRAM_ID,
+          eventCreator: wallet.publicKey,
+          buyer: randomKeypair.publicKey,
+          presaleClaim: presaleClaimPda,
+        })
+        .instruction();
+
+      return provider.sendAndConfirm(new Transaction().add(computeIx, ix), [randomKeypair]);
+    };
+
+    const tx = await buy();
+    console.log("Buy presale token tx signature:", tx);
+
+    const presaleClaimAccount = await program.account.presaleClaim.fetch(presaleClaimPda);
+    expect(presaleClaimAccount.claimed.toNumber()).to.equal(1);
+
+    try {
+      const signature = await buy();
+      console.error("Unexpected buy token tx signature:", signature);
+      return expect.fail("Unexpected buy token tx signature:", signature);
+    } catch (err) {
+      console.log("Expected error buying presale token:", err?.logs?.slice(-3));
+      return expect(err.logs.join(" ")).to.include("PresaleAllocationExceeded");
+    }
+  });
+
+  it("Should fail to buy more tokens than the wallet limit of a Digital Access!", async () => {
+    const eventId = 3;
+    const digitalAccessId = 3;
+    const uri =
+      "https://raw.githubusercontent.com/franRappazzini/boltick-contracts/main/tests/utils/uri-test.json";
+
+    await program.methods
+      .addDigitalAccess(
+        bn(eventId),
+        bn(0.05 * LAMPORTS_PER_SOL),
+        bn(10),
+        "One Per Wallet",
+        "OPW",
+        "Limited to one ticket per wallet",
+        uri,
+        null,
+        null,
+        null,
+        true,
+        bn(1)
+      )
+      .accounts({ eventRole: null })
+      .rpc();
+
+    const [eventPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from(SEED_EVENT), bn(eventId).toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+    const [buyerRecordPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from(SEED_BUYER_RECORD), eventPda.toBuffer(), wallet.publicKey.toBuffer()],
+      program.programId
+    );
+
+    const computeIx = ComputeBudgetProgram.setComputeUnitLimit({ units: 300_000 });
+    const buy = async () => {
+      const ix = await program.methods
+        .buyToken(bn(eventId), digitalAccessId, null, null, null, bn(MAX_PRICE))
+        .accounts({
+          tokenProgram: TOKEN_PROGRAM_ID,
+          eventCreator: wallet.publicKey,
+          buyerRecord: buyerRecordPda,
+        })
+        .instruction();
+
+      return provider.sendAndConfirm(new Transaction().add(computeIx, ix));
+    };
+
+    const tx = await buy();
+    console.log("Buy limited token tx signature:", tx);
+
+    const buyerRecordAccount = await program.account.buyerRecord.fetch(buyerRecordPda);
+    expect(buyerRecordAccount.tiers[0].purchased.toNumber()).to.equal(1);
+
+    try {
+      const signature = await buy();
+      console.error("Unexpected buy token tx signature:", signature);
+      return expect.fail("Unexpected buy token tx signature:", signature);
+    } catch (err) {
+      console.log("Expected error buying token:", err?.logs?.slice(-3));
+      return expect(err.logs.join(" ")).to.include("PurchaseLimitExceeded");
+    }
+  });
+
+  it("Should mint a batch of tokens in one transaction!", async () => {
+    const eventId = 3;
+    const digitalAccessId = 3;
+    const quantity = 2;
+
+    const [eventPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from(SEED_EVENT), bn(eventId).toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+    const eventAccount = await program.account.event.fetch(eventPda);
+    const firstNftId = eventAccount.currentNftCount.toNumber();
+
+    // token_mint, destination_token_account, ticket, metadata_account, edition_account
+    const remainingAccounts = [];
+    for (let nftId = firstNftId; nftId < firstNftId + quantity; nftId++) {
+      const [nftPda] = anchor.web3.PublicKey.findProgramAddressSync(
+        [
+          Buffer.from(SEED_TOKEN_MINT),
+          eventAccount.collectionMintAccount.toBuffer(),
+          bn(nftId).toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+      const [ticketPda] = anchor.web3.PublicKey.findProgramAddressSync(
+        [Buffer.from(SEED_TICKET), nftPda.toBuffer()],
+        program.programId
+      );
+      const [metadataPda] = anchor.web3.PublicKey.findProgramAddressSync(
+        [Buffer.from("metadata"), TOKEN_METADATA_PROGRAM_ID.toBuffer(), nftPda.toBuffer()],
+        TOKEN_METADATA_PROGRAM_ID
+      );
+      const [editionPda] = anchor.web3.PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("metadata"),
+          TOKEN_METADATA_PROGRAM_ID.toBuffer(),
+          nftPda.toBuffer(),
+          Buffer.from("edition"),
+        ],
+        TOKEN_METADATA_PROGRAM_ID
+      );
+
+      remainingAccounts.push(
+        ...[
+          nftPda,
+          getAssociatedTokenAddressSync(nftPda, wallet.publicKey),
+          ticketPda,
+          metadataPda,
+          editionPda,
+        ].map((pubkey) => ({ pubkey, isSigner: false, isWritable: true }))
+      );
+    }
+
+    const computeIx = ComputeBudgetProgram.setComputeUnitLimit({ units: 1_400_000 });
+    const ix = await program.methods
+      .mintTokensBatch(bn(eventId), digitalAccessId, quantity)
+      .accounts({ eventRole: null, tokenProgram: TOKEN_PROGRAM_ID, destination: wallet.publicKey })
+      .remainingAccounts(remainingAccounts)
+      .instruction();
+
+    // lamports sent to the next mint address ahead of time must not block the batch
+    await provider.sendAndConfirm(
+      new Transaction().add(
+        anchor.web3.SystemProgram.transfer({
+          fromPubkey: wallet.publicKey,
+          toPubkey: remainingAccounts[0].pubkey,
+          lamports: await connection.getMinimumBalanceForRentExemption(0),
+        })
+      )
+    );
+
+    const tx = await provider.sendAndConfirm(new Transaction().add(computeIx, ix));
+    console.log("Mint tokens batch tx signature:", tx);
+
+    const updatedEventAccount = await program.account.event.fetch(eventPda);
+    expect(updatedEventAccount.currentNftCount.toNumber()).to.equal(firstNftId + quantity);
+  });
+
+  it("Should buy a token with a discount code only while it has uses left!", async () => {
+    const eventId = 3;
+    const price = 0.1 * LAMPORTS_PER_SOL;
+    const code = "BOLTICK50";
+    const codeHash = sha256(Buffer.from(code));
+    const uri =
+      "https://raw.githubusercontent.com/franRappazzini/boltick-contracts/main/tests/utils/uri-test.json";
+
+    const [eventPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from(SEED_EVENT), bn(eventId).toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+    const digitalAccessId = (await program.account.event.fetch(eventPda))
+      .currentDigitalAccessCount;
+
+    await program.methods
+      .addDigitalAccess(
+        bn(eventId),
+        bn(price),
+        bn(10),
+        "Discounted",
+        "DSC",
+        "Digital Access with discount codes",
+        uri,
+        null,
+        null,
+        null,
+        true,
+        null
+      )
+      .accounts({ eventRole: null })
+      .rpc();
+
+    const tx = await program.methods
+      .createDiscount(bn(eventId), [...codeHash], { percentage: { bps: 5_000 } }, bn(1), null, null)
+      .accounts({ eventRole: null })
+      .rpc();
+    console.log("Create discount tx signature:", tx);
+
+    const [discountPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from(SEED_DISCOUNT), eventPda.toBuffer(), codeHash],
+      program.programId
+    );
+    const computeIx = ComputeBudgetProgram.setComputeUnitLimit({ units: 400_000 });
+    const buyIx = () =>
+      program.methods
+        .buyToken(bn(eventId), digitalAccessId, null, null, code, bn(MAX_PRICE))
+        .accounts({
+          tokenProgram: TOKEN_PROGRAM_ID,
+          eventCreator: wallet.publicKey,
+          discount: discountPda,
+        })
+        .instruction();
+
+    const buyTx = await provider.sendAndConfirm(new Transaction().add(computeIx, await buyIx()));
+    console.log("Buy token with discount tx signature:", buyTx);
+
+    const eventAccount = await program.account.event.fetch(eventPda);
+    const [nftPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from(SEED_TOKEN_MINT),
+        eventAccount.collectionMintAccount.toBuffer(),
+        bn(eventAccount.currentNftCount.toNumber() - 1).toArrayLike(Buffer, "le", 8),
+      ],
+      program.programId
+    );
+    const [ticketPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from(SEED_TICKET), nftPda.toBuffer()],
+      program.programId
+    );
+    const ticketAccount = await program.account.ticket.fetch(ticketPda);
+
+    expect(ticketAccount.pricePaid.toNumber()).to.equal(price / 2);
+
+    try {
+      const tx = await provider.sendAndConfirm(new Transaction().add(computeIx, await buyIx()));
+      console.error("Unexpected buy token tx signature:", tx);
+      return expect.fail("Unexpected buy token tx signature:", tx);
+    } catch (err) {
+      console.log("Expected error buying with a used up discount:", err.logs.join(" "));
+      return expect(err.logs.join(" ")).to.include("DiscountNotApplicable");
+    }
+  });
+
+  it("Should fail to buy a token above the max price once the price steps up!", async () => {
+    const eventId = 3;
+    const price = 0.1 * LAMPORTS_PER_SOL;
+    const increment = 0.05 * LAMPORTS_PER_SOL;
+    const uri =
+      "https://raw.githubusercontent.com/franRappazzini/boltick-contracts/main/tests/utils/uri-test.json";
+
+    const [eventPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from(SEED_EVENT), bn(eventId).toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+    const digitalAccessId = (await program.account.event.fetch(eventPda))
+      .currentDigitalAccessCount;
+
+    await program.methods
+      .addDigitalAccess(
+        bn(eventId),
+        bn(price),
+        bn(10),
+        "Stepped",
+        "STP",
+        "Price goes up with every token sold",
+        uri,
+        null,
+        null,
+        null,
+        true,
+        null
+      )
+      .accounts({ eventRole: null })
+      .rpc();
+
+    const tx = await program.methods
+      .setPricing(bn(eventId), digitalAccessId, {
+        linearStep: { stepSize: bn(1), increment: bn(increment) },
+      })
+      .accounts({ eventRole: null })
+      .rpc();
+    console.log("Set pricing tx signature:", tx);
+
+    const computeIx = ComputeBudgetProgram.setComputeUnitLimit({ units: 400_000 });
+    const buyIx = () =>
+      program.methods
+        .buyToken(bn(eventId), digitalAccessId, null, null, null, bn(price))
+        .accounts({ tokenProgram: TOKEN_PROGRAM_ID, eventCreator: wallet.publicKey })
+        .instruction();
+
+    // the first token is sold at the base price
+    await provider.sendAndConfirm(new Transaction().add(computeIx, await buyIx()));
+
+    try {
+      const tx = await provider.sendAndConfirm(new Transaction().add(computeIx, await buyIx()));
+      console.error("Unexpected buy token tx signature:", tx);
+      return expect.fail("Unexpected buy token tx signature:", tx);
+    } catch (err) {
+      console.log("Expected error buying above the max price:", err.logs.join(" "));
+      return expect(err.logs.join(" ")).to.include("PriceAboveMax");
+    }
+  });
+
+  it("Should mint to the waitlist once a sold out Digital Access gets more supply!", async () => {
+    const eventId = 3;
+    const price = 0.1 * LAMPORTS_PER_SOL;
+    const uri =
+      "https://raw.githubusercontent.com/franRappazzini/boltick-contracts/main/tests/utils/uri-test.json";
+
+    const [eventPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from(SEED_EVENT), bn(eventId).toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+    const eventAccount = await program.account.event.fetch(eventPda);
+    const digitalAccessId = eventAccount.currentDigitalAccessCount;
+    const [digitalAccessPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from(SEED_DIGITAL_ACCESS), eventPda.toBuffer(), Buffer.from([digitalAccessId])],
+      program.programId
+    );
+    const waitlistEntryPda = (position: number) =>
+      anchor.web3.PublicKey.findProgramAddressSync(
+        [
+          Buffer.from(SEED_WAITLIST_ENTRY),
+          digitalAccessPda.toBuffer(),
+          bn(position).toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      )[0];
+
+    await program.methods
+      .addDigitalAccess(
+        bn(eventId),
+        bn(price),
+        bn(1),
+        "Waitlist",
+        "WL",
+        "Digital Access with a waitlist",
+        uri,
+        null,
+        null,
+        null,
+        true,
+        null
+      )
+      .accounts({ eventRole: null })
+      .rpc();
+
+    const computeIx = ComputeBudgetProgram.setComputeUnitLimit({ units: 400_000 });
+    const buyIx = () =>
+      program.methods
+        .buyToken(bn(eventId), digitalAccessId, null, null, null, bn(MAX_PRICE))
+        .accounts({ tokenProgram: TOKEN_PROGRAM_ID, eventCreator: wallet.publicKey })
+        .instruction();
+
+    // sells the digital access out
+    await provider.sendAndConfirm(new Transaction().add(computeIx, await buyIx()));
+
+    await program.methods.joinWaitlist(bn(eventId), digitalAccessId).rpc();
+    const joinTx = await program.methods
+      .joinWaitlist(bn(eventId), digitalAccessId)
+      .accounts({ buyer: randomKeypair.publicKey })
+      .signers([randomKeypair])
+      .rpc();
+    console.log("Join waitlist tx signature:", joinTx);
+
+    const leaveTx = await program.methods.leaveWaitlist(bn(eventId), digitalAccessId, bn(0)).rpc();
+    console.log("Leave waitlist tx signature:", leaveTx);
+
+    await program.methods
+      .updateDigitalAccess(bn(eventId), digitalAccessId, null, bn(2), null, null, null)
+      .accounts({ eventRole: null })
+      .rpc();
+
+    try {
+      const tx = await provider.sendAndConfirm(new Transaction().add(computeIx, await buyIx()));
+      console.error("Unexpected buy token tx signature:", tx);
+      return expect.fail("Unexpected buy token tx signature:", tx);
+    } catch (err) {
+      console.log("Expected error buying ahead of the waitlist:", err.logs.join(" "));
+      expect(err.logs.join(" ")).to.include("WaitlistNotEmpty");
+    }
+
+    try {
+      const mintIx = await program.methods
+        .mintToken(bn(eventId), digitalAccessId)
+        .accounts({
+          eventRole: null,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          destination: wallet.publicKey,
+        })
+        .instruction();
+      const tx = await provider.sendAndConfirm(new Transaction().add(computeIx, mintIx));
+      console.error("Unexpected mint token tx signature:", tx);
+      return expect.fail("Unexpected mint token tx signature:", tx);
+    } catch (err) {
+      console.log("Expected error minting ahead of the waitlist:", err.logs.join(" "));
+      expect(err.logs.join(" ")).to.include("WaitlistNotEmpty");
+    }
+
+    // the entry that left is skipped on the way to the next buyer in line
+    const processIx = await program.methods
+      .processWaitlist(bn(eventId), digitalAccessId, bn(1))
+      .accounts({
+        tokenProgram: TOKEN_PROGRAM_ID,
+        eventCreator: wallet.publicKey,
+        buyer: randomKeypair.publicKey,
+      })
+      .remainingAccounts([{ pubkey: waitlistEntryPda(0), isSigner: false, isWritable: false }])
+      .instruction();
+    const processTx = await provider.sendAndConfirm(new Transaction().add(computeIx, processIx));
+    console.log("Process waitlist tx signature:", processTx);
+
+    const [nftPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from(SEED_TOKEN_MINT),
+        eventAccount.collectionMintAccount.toBuffer(),
+        eventAccount.currentNftCount.addn(1).toArrayLike(Buffer, "le", 8),
+      ],
+      program.programId
+    );
+    const tokenAccount = await getAccount(
+      connection,
+      getAssociatedTokenAddressSync(nftPda, randomKeypair.publicKey)
+    );
+    const digitalAccessAccount = await program.account.digitalAccess.fetch(digitalAccessPda);
+
+    expect(Number(tokenAccount.amount)).to.equal(1);
+    expect(digitalAccessAccount.currentMinted.toNumber()).to.equal(2);
+    expect(digitalAccessAccount.waitlistLen.toNumber()).to.equal(0);
+    expect(await connection.getAccountInfo(waitlistEntryPda(1))).to.be.null;
+  });
+
+  it("Should upgrade a ticket to a higher Digital Access paying only the difference!", async () => {
+    const eventId = 3;
+    const price = 0.1 * LAMPORTS_PER_SOL;
+    const vipPrice = 0.3 * LAMPORTS_PER_SOL;
+    const uri =
+      "https://raw.githubusercontent.com/franRappazzini/boltick-contracts/main/tests/utils/uri-test.json";
+
+    const [eventPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from(SEED_EVENT), bn(eventId).toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+    const eventAccount = await program.account.event.fetch(eventPda);
+    const generalId = eventAccount.currentDigitalAccessCount;
+    const vipId = generalId + 1;
+    const tokenId = eventAccount.currentNftCount;
+
+    for (const [tierPrice, name, symbol] of [
+      [price, "General Admission", "GA"],
+      [vipPrice, "VIP", "VIP"],
+    ] as const) {
+      await program.methods
+        .addDigitalAccess(
+          bn(eventId),
+          bn(tierPrice),
+          bn(10),
+          name,
+          symbol,
+          `${name} tickets`,
+          uri,
+          null,
+          null,
+          null,
+          true,
+          null
+        )
+        .accounts({ eventRole: null })
+        .rpc();
+    }
+
+    const digitalAccessPda = (id: number) =>
+      anchor.web3.PublicKey.findProgramAddressSync(
+        [Buffer.from(SEED_DIGITAL_ACCESS), eventPda.toBuffer(), Buffer.from([id])],
+        program.programId
+      )[0];
+
+    const computeIx = ComputeBudgetProgram.setComputeUnitLimit({ units: 400_000 });
+    const buyIx = await program.methods
+      .buyToken(bn(eventId), generalId, null, null, null, bn(MAX_PRICE))
+      .accounts({ tokenProgram: TOKEN_PROGRAM_ID, eventCreator: wallet.publicKey })
+      .instruction();
+    await provider.sendAndConfirm(new Transaction().add(computeIx, buyIx));
+
+    const tx = await program.methods
+      .upgradeTicket(bn(eventId), tokenId, vipId, bn(vipPrice - price))
+      .accounts({
+        tokenProgram: TOKEN_PROGRAM_ID,
+        eventCreator: wallet.publicKey,
+        fromDigitalAccess: digitalAccessPda(generalId),
+      })
+      .rpc();
+    console.log("Upgrade ticket tx signature:", tx);
+
+    const [nftPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from(SEED_TOKEN_MINT),
+        eventAccount.collectionMintAccount.toBuffer(),
+        tokenId.toArrayLike(Buffer, "le", 8),
+      ],
+      program.programId
+    );
+    const [ticketPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from(SEED_TICKET), nftPda.toBuffer()],
+      program.programId
+    );
+    const ticketAccount = await program.account.ticket.fetch(ticketPda);
+    const general = await program.account.digitalAccess.fetch(digitalAccessPda(generalId));
+    const vip = await program.account.digitalAccess.fetch(digitalAccessPda(vipId));
+
+    expect(ticketAccount.digitalAccess.toBase58()).to.equal(digitalAccessPda(vipId).toBase58());
+    expect(ticketAccount.pricePaid.toNumber()).to.equal(vipPrice);
+    expect(general.currentMinted.toNumber()).to.equal(0);
+    expect(vip.currentMinted.toNumber()).to.equal(1);
+  });
+
+  it("Should buy a compressed ticket from a Bubblegum tree!", async () => {
+    const eventId = 4;
+    const digitalAccessId = 0;
+    const maxDepth = 3;
+    const maxBufferSize = 8;
+    const now = Math.floor(Date.now() / 1000);
+    const uri =
+      "https://raw.githubusercontent.com/franRappazzini/boltick-contracts/main/tests/utils/uri-test.json";
+
+    await program.methods
+      .initializeEvent(
+        "Compressed Event",
+        "CE",
+        uri,
+        "Compressed Event",
+        bn(now + DAY),
+        bn(now + 2 * DAY),
+        bn(now - 60),
+        bn(now + DAY),
+        false,
+        0,
+        [],
+        null
+      )
+      .accounts({ tokenProgram: TOKEN_PROGRAM_ID })
+      .rpc();
+
+    // concurrent merkle tree account: header + tree(maxDepth, maxBufferSize), no canopy
+    const merkleTree = anchor.web3.Keypair.generate();
+    const nodeSize = 32 * maxDepth + 32 + 8;
+    const space = 56 + 24 + maxBufferSize * nodeSize + nodeSize;
+    const allocateIx = anchor.web3.SystemProgram.createAccount({
+      fromPubkey: wallet.publicKey,
+      newAccountPubkey: merkleTree.publicKey,
+      lamports: await connection.getMinimumBalanceForRentExemption(space),
+      space,
+      programId: ACCOUNT_COMPRESSION_PROGRAM_ID,
+    });
+
+    const [treeAuthority] = anchor.web3.PublicKey.findProgramAddressSync(
+      [merkleTree.publicKey.toBuffer()],
+      BUBBLEGUM_PROGRAM_ID
+    );
+    const programs = {
+      bubblegumProgram: BUBBLEGUM_PROGRAM_ID,
+      compressionProgram: ACCOUNT_COMPRESSION_PROGRAM_ID,
+      logWrapper: NOOP_PROGRAM_ID,
+    };
+
+    const createTreeIx = await program.methods
+      .createTicketTree(bn(eventId), maxDepth, maxBufferSize)
+      .accounts({ merkleTree: merkleTree.publicKey, treeAuthority, ...programs })
+      .instruction();
+    const treeTx = await provider.sendAndConfirm(new Transaction().add(allocateIx, createTreeIx), [
+      merkleTree,
+    ]);
+    console.log("Create ticket tree tx signature:", treeTx);
+
+    await program.methods
+      .addDigitalAccess(
+        bn(eventId),
+        bn(0.01 * LAMPORTS_PER_SOL),
+        bn(10),
+        "Compressed",
+        "CMP",
+        "Compressed ticket",
+        uri,
+        null,
+        null,
+        null,
+        true,
+        null
+      )
+      .accounts({ eventRole: null })
+      .rpc();
+
+    const computeIx = ComputeBudgetProgram.setComputeUnitLimit({ units: 400_000 });
+    const buyIx = await program.methods
+      .buyCompressedToken(bn(eventId), digitalAccessId, bn(0.01 * LAMPORTS_PER_SOL))
+      .accounts({
+        buyer: randomKeypair.publicKey,
+        eventCreator: wallet.publicKey,
+        merkleTree: merkleTree.publicKey,
+        treeAuthority,
+        ...programs,
+      })
+      .instruction();
+    const buyTx = await provider.sendAndConfirm(new Transaction().add(computeIx, buyIx), [
+      randomKeypair,
+    ]);
+    console.log("Buy compressed token tx signature:", buyTx);
+
+    const [eventPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from(SEED_EVENT), bn(eventId).toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+    const eventAccount = await program.account.event.fetch(eventPda);
+
+    expect(eventAccount.merkleTree.toBase58()).to.equal(merkleTree.publicKey.toBase58());
+    expect(eventAccount.currentNftCount.toNumber()).to.equal(1);
+  });
+
+  it("Should redeem a compressed ticket and update its uri with a leaf proof!", async () => {
+    const eventId = 4;
+    const nonce = 0;
+    const uri =
+      "https://raw.githubusercontent.com/franRappazzini/boltick-contracts/main/tests/utils/uri-test.json";
+    const updatedUri =
+      "https://raw.githubusercontent.com/franRappazzini/boltick-contracts/main/tests/utils/uri-test-update.json";
+
+    const [eventPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from(SEED_EVENT), bn(eventId).toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+    const [collectionMint] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from(SEED_COLLECTION_MINT), bn(eventId).toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+    const merkleTree = (await program.account.event.fetch(eventPda)).merkleTree;
+    const [treeAuthority] = anchor.web3.PublicKey.findProgramAddressSync(
+      [merkleTree.toBuffer()],
+      BUBBLEGUM_PROGRAM_ID
+    );
+    const [assetId] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("asset"), merkleTree.toBuffer(), bn(nonce).toArrayLike(Buffer, "le", 8)],
+      BUBBLEGUM_PROGRAM_ID
+    );
+    const [redemptionPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from(SEED_REDEMPTION), assetId.toBuffer()],
+      program.programId
+    );
+    const programs = {
+      bubblegumProgram: BUBBLEGUM_PROGRAM_ID,
+      compressionProgram: ACCOUNT_COMPRESSION_PROGRAM_ID,
+      logWrapper: NOOP_PROGRAM_ID,
+    };
+
+    try {
+      const tx = await program.methods
+        .addDigitalAccess(
+          bn(eventId),
+          bn(0.01 * LAMPORTS_PER_SOL),
+          bn(10),
+          "Soulbound",
+          "SB",
+          "Soulbound compressed ticket",
+          uri,
+          null,
+          null,
+          null,
+          false,
+          null
+        )
+        .accounts({ eventRole: null })
+        .rpc();
+      console.error("Unexpected add digital access tx signature:", tx);
+      return expect.fail("Unexpected add digital access tx signature:", tx);
+    } catch (err) {
+      console.log("Expected error adding a soulbound tier:", err?.error?.errorCode?.code);
+      expect(err.error.errorCode.code).to.equal("CompressedTierNotSupported");
+    }
+
+    // the leaf as hashed by bubblegum, which verifies the collection on mint
+    const metadata = {
+      name: "Compressed #0",
+      symbol: "CMP",
+      uri,
+      sellerFeeBasisPoints: 0,
+      primarySaleHappened: false,
+      isMutable: true,
+      editionNonce: null,
+      tokenStandard: { nonFungible: {} },
+      collection: { verified: true, key: collectionMint },
+      uses: null,
+      tokenProgramVersion: { original: {} },
+      creators: [{ address: collectionMint, verified: true, share: 100 }],
+    };
+    const dataHash = keccak(
+      keccak(program.coder.types.encode("bubblegumMetadataArgs", metadata)),
+      Buffer.from([0, 0]) // seller_fee_basis_points
+    );
+    const creatorHash = keccak(collectionMint.toBuffer(), Buffer.from([1, 100]));
+    const leaf = keccak(
+      Buffer.from([1]),
+      assetId.toBuffer(),
+      randomKeypair.publicKey.toBuffer(),
+      randomKeypair.publicKey.toBuffer(),
+      bn(nonce).toArrayLike(Buffer, "le", 8),
+      dataHash,
+      creatorHash
+    );
+
+    // the only leaf sits at index 0, so the proof is the empty subtree of every level
+    const proof = [Buffer.alloc(32)];
+    for (let depth = 1; depth < 3; depth++) {
+      proof.push(keccak(proof[depth - 1], proof[depth - 1]));
+    }
+    const root = proof.reduce((node, sibling) => keccak(node, sibling), leaf);
+    const proofAccounts = proof.map((node) => ({
+      pubkey: new anchor.web3.PublicKey(node),
+      isSigner: false,
+      isWritable: false,
+    }));
+
+    const redeemTx = await program.methods
+      .redeemCompressedToken(bn(eventId), [...root], [...dataHash], [...creatorHash], bn(nonce), 0)
+      .accounts({
+        eventRole: null,
+        leafOwner: randomKeypair.publicKey,
+        leafDelegate: randomKeypair.publicKey,
+        merkleTree,
+        redemption: redemptionPda,
+        compressionProgram: ACCOUNT_COMPRESSION_PROGRAM_ID,
+      })
+      .remainingAccounts(proofAccounts)
+      .rpc();
+    console.log("Redeem compressed token tx signature:", redeemTx);
+
+    const redemptionAccount = await program.account.redemption.fetch(redemptionPda);
+    expect(redemptionAccount.redeemed).to.equal(true);
+    expect(redemptionAccount.tokenMint.toBase58()).to.equal(assetId.toBase58());
+
+    const computeIx = ComputeBudgetProgram.setComputeUnitLimit({ units: 400_000 });
+    const updateIx = await program.methods
+      .updateCompressedTokenMetadata(bn(eventId), [...root], bn(nonce), 0, metadata, updatedUri)
+      .accounts({
+        leafOwner: randomKeypair.publicKey,
+        leafDelegate: randomKeypair.publicKey,
+        merkleTree,
+        treeAuthority,
+        ...programs,
+      })
+      .remainingAccounts(proofAccounts)
+      .instruction();
+    const updateTx = await provider.sendAndConfirm(new Transaction().add(computeIx, updateIx));
+    console.log("Update compressed token metadata tx signature:", updateTx);
+
+  });
+
+  it("Should buy a Token-2022 ticket with metadata and group extensions!", async () => {
+    const eventId = 5;
+    const digitalAccessId = 0;
+    const now = Math.floor(Date.now() / 1000);
+    const uri =
+      "https://raw.githubusercontent.com/franRappazzini/boltick-contracts/main/tests/utils/uri-test.json";
+
+    const tx = await program.methods
+      .initializeToken2022Event(
+        "Token-2022 Event",
+        "T22",
+        uri,
+        "Token-2022 Event",
+        bn(now + DAY),
+        bn(now + 2 * DAY),
+        bn(now - 60),
+        bn(now + DAY),
+        0,
+        []
+      )
+      .accounts({ tokenProgram: TOKEN_2022_PROGRAM_ID })
+      .rpc();
+    console.log("Initialize Token-2022 event tx signature:", tx);
+
+    await program.methods
+      .addDigitalAccess(
+        bn(eventId),
+        bn(0.01 * LAMPORTS_PER_SOL),
+        bn(10),
+        "Token-2022",
+        "T22",
+        "Token-2022 ticket",
+        uri,
+        null,
+        null,
+        null,
+        true,
+        null
+      )
+      .accounts({ eventRole: null })
+      .rpc();
+
+    const buyToken2022 = (maxPrice: number) =>
+      program.methods
+        .buyToken2022(bn(eventId), digitalAccessId, bn(maxPrice))
+        .accounts({
+          buyer: randomKeypair.publicKey,
+          eventCreator: wallet.publicKey,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+        })
+        .signers([randomKeypair])
+        .rpc();
+
+    try {
+      const tx = await buyToken2022(0.005 * LAMPORTS_PER_SOL);
+      console.error("Unexpected buy Token-2022 token tx signature:", tx);
+      return expect.fail("Unexpected buy Token-2022 token tx signature:", tx);
+    } catch (err) {
+      console.log("Expected error buying above the max price:", err?.error?.errorCode?.code);
+      expect(err.error.errorCode.code).to.equal("PriceAboveMax");
+    }
+
+    const buyTx = await buyToken2022(0.01 * LAMPORTS_PER_SOL);
+    console.log("Buy Token-2022 token tx signature:", buyTx);
+
+    const [collectionMint] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from(SEED_COLLECTION_MINT), bn(eventId).toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+    const [tokenMint] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from(SEED_TOKEN_MINT),
+        collectionMint.toBuffer(),
+        bn(0).toArrayLike(Buffer, "le", 8),
+      ],
+      program.programId
+    );
+    const buyerTokenAccount = getAssociatedTokenAddressSync(
+      tokenMint,
+      randomKeypair.publicKey,
+      false,
+      TOKEN_2022_PROGRAM_ID
+    );
+
+    const tokenAccount = await getAccount(
+      connection,
+      buyerTokenAccount,
+      undefined,
+      TOKEN_2022_PROGRAM_ID
+    );
+    const metadata = await getTokenMetadata(connection, tokenMint);
+
+    expect(Number(tokenAccount.amount)).to.equal(1);
+    expect(metadata.name).to.equal("Token-2022 #0");
+    expect(metadata.updateAuthority.toBase58()).to.equal(collectionMint.toBase58());
+  });
+
+  it("Should burn a ticket and close the Event after it is cancelled!", async () => {
+    const eventId = 5;
+    const nftId = 0;
+
+    const [eventPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from(SEED_EVENT), bn(eventId).toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+    const [digitalAccessPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from(SEED_DIGITAL_ACCESS), eventPda.toBuffer(), Buffer.from([0])],
+      program.programId
+    );
+
+    await program.methods.cancelEvent(bn(eventId)).rpc();
+
+    try {
+      const tx = await program.methods
+        .closeEvent(bn(eventId))
+        .remainingAccounts([{ pubkey: digitalAccessPda, isSigner: false, isWritable: true }])
+        .rpc();
+      console.error("Unexpected close event tx signature:", tx);
+      return expect.fail("Unexpected close event tx signature:", tx);
+    } catch (err) {
+      console.log("Expected error closing an event with tickets:", err?.error?.errorCode?.code);
+      expect(err.error.errorCode.code).to.equal("TicketsOutstanding");
+    }
+
+    const burnTx = await program.methods
+      .burnTicket(bn(eventId), bn(nftId))
+      .accounts({ holder: randomKeypair.publicKey, tokenProgram: TOKEN_2022_PROGRAM_ID })
+      .signers([randomKeypair])
+      .rpc();
+    console.log("Burn ticket tx signature:", burnTx);
+
+    const closeTx = await program.methods
+      .closeEvent(bn(eventId))
+      .remainingAccounts([{ pubkey: digitalAccessPda, isSigner: false, isWritable: true }])
+      .rpc();
+    console.log("Close event tx signature:", closeTx);
+
+    expect(await connection.getAccountInfo(eventPda)).to.be.null;
+    expect(await connection.getAccountInfo(digitalAccessPda)).to.be.null;
+  });
+
+  it("Should rebate the clearing price difference of a Dutch auction after settling!", async () => {
+    const eventId = 6;
+    const digitalAccessId = 0;
+    const now = Math.floor(Date.now() / 1000);
+    const uri =
+      "https://raw.githubusercontent.com/franRappazzini/boltick-contracts/main/tests/utils/uri-test.json";
+
+    await program.methods
+      .initializeEvent(
+        "Auction Event",
+        "AE",
+        uri,
+        "Auction Event",
+        bn(now - 120),
+        bn(now + 22),
+        bn(now - 60),
+        bn(now + 20),
+        true,
+        0,
+        [],
+        null
+      )
+      .accounts({ tokenProgram: TOKEN_PROGRAM_ID })
+      .rpc();
+
+    await program.methods
+      .addDigitalAccess(
+        bn(eventId),
+        bn(0),
+        bn(2),
+        "VIP Auction",
+        "VIP",
+        "VIP tickets sold by Dutch auction",
+        uri,
+        null,
+        null,
+        null,
+        true,
+        null
+      )
+      .accounts({ eventRole: null })
+      .rpc();
+
+    const tx = await program.methods
+      .setPricing(bn(eventId), digitalAccessId, {
+        dutchAuction: {
+          startTime: bn(now - 10),
+          startPrice: bn(0.2 * LAMPORTS_PER_SOL),
+          floorPrice: bn(0.1 * LAMPORTS_PER_SOL),
+          interval: bn(1),
+          decrement: bn(0.001 * LAMPORTS_PER_SOL),
+        },
+      })
+      .accounts({ eventRole: null })
+      .rpc();
+    console.log("Set Dutch auction pricing tx signature:", tx);
+
+    const code = "AUCTION10";
+    const codeHash = sha256(Buffer.from(code));
+    await program.methods
+      .createDiscount(bn(eventId), [...codeHash], { percentage: { bps: 1_000 } }, bn(1), null, null)
+      .accounts({ eventRole: null })
+      .rpc();
+
+    const [eventPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from(SEED_EVENT), bn(eventId).toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+    const [digitalAccessPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from(SEED_DIGITAL_ACCESS), eventPda.toBuffer(), Buffer.from([digitalAccessId])],
+      program.programId
+    );
+    const [vaultPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from(SEED_VAULT), eventPda.toBuffer()],
+      program.programId
+    );
+    const [discountPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from(SEED_DISCOUNT), eventPda.toBuffer(), codeHash],
+      program.programId
+    );
+
+    const computeIx = ComputeBudgetProgram.setComputeUnitLimit({ units: 400_000 });
+    const buyIx = (discountCode: string | null) =>
+      program.methods
+        .buyToken(bn(eventId), digitalAccessId, null, null, discountCode, bn(MAX_PRICE))
+        .accounts({
+          tokenProgram: TOKEN_PROGRAM_ID,
+          eventCreator: wallet.publicKey,
+          discount: discountCode ? discountPda : null,
+        })
+        .instruction();
+
+    // the first bid is discounted, the second purchase sells the tier out at a lower price
+    await provider.sendAndConfirm(new Transaction().add(computeIx, await buyIx(code)));
+    await new Promise((resolve) => setTimeout(resolve, 3000));
+    await provider.sendAndConfirm(new Transaction().add(computeIx, await buyIx(null)));
+
+    try {
+      const tx = await program.methods
+        .setPricing(bn(eventId), digitalAccessId, { flat: {} })
+        .accounts({ eventRole: null })
+        .rpc();
+      console.error("Unexpected set pricing tx signature:", tx);
+      return expect.fail("Unexpected set pricing tx signature:", tx);
+    } catch (err) {
+      console.log("Expected error leaving the auction:", err?.error?.errorCode?.code);
+      expect(err.error.errorCode.code).to.equal("AuctionPricingLocked");
+    }
+
+    // more supply would reopen the auction and pay its rebates a second time
+    try {
+      const tx = await program.methods
+        .updateDigitalAccess(bn(eventId), digitalAccessId, null, bn(3), null, null, null)
+        .accounts({ eventRole: null })
+        .rpc();
+      console.error("Unexpected update digital access tx signature:", tx);
+      return expect.fail("Unexpected update digital access tx signature:", tx);
+    } catch (err) {
+      console.log("Expected error resizing the auction:", err?.error?.errorCode?.code);
+      expect(err.error.errorCode.code).to.equal("AuctionSupplyLocked");
+    }
+
+    const eventAccount = await program.account.event.fetch(eventPda);
+    const [nftPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from(SEED_TOKEN_MINT),
+        eventAccount.collectionMintAccount.toBuffer(),
+        bn(0).toArrayLike(Buffer, "le", 8),
+      ],
+      program.programId
+    );
+    const [ticketPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from(SEED_TICKET), nftPda.toBuffer()],
+      program.programId
+    );
+    const bid = await program.account.ticket.fetch(ticketPda);
+    const digitalAccessAccount = await program.account.digitalAccess.fetch(digitalAccessPda);
+    const clearingPrice = digitalAccessAccount.clearingPrice.toNumber();
+    const rebate = bid.basePrice.toNumber() - clearingPrice;
+    const rentExempt = await connection.getMinimumBalanceForRentExemption(0);
+
+    // settle once the event is over, the unclaimed rebate has to stay in the vault
+    const untilEnd = (now + 23) * 1000 - Date.now();
+    await new Promise((resolve) => setTimeout(resolve, Math.max(untilEnd, 0)));
+
+    const settleTx = await program.methods
+      .settleEvent(bn(eventId))
+      .accounts({ eventCreator: wallet.publicKey })
+      .remainingAccounts([{ pubkey: digitalAccessPda, isSigner: false, isWritable: false }])
+      .rpc();
+    console.log("Settle event tx signature:", settleTx);
+
+    expect(await connection.getBalance(vaultPda)).to.equal(rentExempt + rebate);
+
+    // closing the settled event must not sweep the rebate still owed to the first bidder
+    try {
+      const tx = await program.methods
+        .closeEvent(bn(eventId))
+        .remainingAccounts([{ pubkey: digitalAccessPda, isSigner: false, isWritable: true }])
+        .rpc();
+      console.error("Unexpected close event tx signature:", tx);
+      return expect.fail("Unexpected close event tx signature:", tx);
+    } catch (err) {
+      console.log("Expected error closing with a rebate owed:", err?.error?.errorCode?.code);
+      expect(err.error.errorCode.code).to.equal("TicketsOutstanding");
+    }
+    expect(await connection.getBalance(vaultPda)).to.equal(rentExempt + rebate);
+
+    const rebateTx = await program.methods
+      .claimRebate(bn(eventId), digitalAccessId, bn(0))
+      .accounts({ tokenProgram: TOKEN_PROGRAM_ID })
+      .rpc();
+    console.log("Claim rebate tx signature:", rebateTx);
+
+    const ticketAccount = await program.account.ticket.fetch(ticketPda);
+
+    // the discount taken on the first bid is kept on top of the rebate
+    expect(clearingPrice).to.be.lessThan(bid.basePrice.toNumber());
+    expect(bid.pricePaid.toNumber()).to.be.lessThan(bid.basePrice.toNumber());
+    expect(ticketAccount.pricePaid.toNumber()).to.equal(bid.pricePaid.toNumber() - rebate);
+    expect(ticketAccount.basePrice.toNumber()).to.equal(clearingPrice);
+    expect(await connection.getBalance(vaultPda)).to.equal(rentExempt);
+  });
+
+  it("Should close an escrowed Event and its vault token account once tickets are refunded!", async () => {
+    const eventId = 7;
+    const digitalAccessId = 0;
+    const nftId = 0;
+    const price = 10_000_000; // 10 tokens with 6 decimals
+    const now = Math.floor(Date.now() / 1000);
+    const uri =
+      "https://raw.githubusercontent.com/franRappazzini/boltick-contracts/main/tests/utils/uri-test.json";
+
+    await program.methods
+      .initializeEvent(
+        "Escrowed USDC Event",
+        "EU",
+        uri,
+        "Escrowed USDC Event",
+        bn(now + DAY),
+        bn(now + 2 * DAY),
+        bn(now - 60),
+        bn(now + DAY),
+        true,
+        0,
+        [],
+        null
+      )
+      .accounts({ tokenProgram: TOKEN_PROGRAM_ID })
+      .rpc();
+
+    await program.methods
+      .addDigitalAccess(
+        bn(eventId),
+        bn(price),
+        bn(10),
+        "USDC Access",
+        "USDC",
+        "Paid in USDC",
+        uri,
+        null,
+        null,
+        null,
+        true,
+        null
+      )
+      .accounts({ eventRole: null, paymentMint: splPaymentMint })
+      .rpc();
+
+    const [eventPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from(SEED_EVENT), bn(eventId).toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+    const [vaultPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from(SEED_VAULT), eventPda.toBuffer()],
+      program.programId
+    );
+    const [digitalAccessPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from(SEED_DIGITAL_ACCESS), eventPda.toBuffer(), Buffer.from([digitalAccessId])],
+      program.programId
+    );
+    const buyerAta = await getOrCreateAssociatedTokenAccount(
+      connection,
+      wallet.payer,
+      splPaymentMint,
+      wallet.publicKey
+    );
+    const vaultAta = getAssociatedTokenAddressSync(splPaymentMint, vaultPda, true);
+    const buyerPrevBalance = Number((await getAccount(connection, buyerAta.address)).amount);
+    await mintTo(connection, wallet.payer, splPaymentMint, buyerAta.address, wallet.payer, price);
+
+    const paymentAccounts = {
+      paymentMint: splPaymentMint,
+      vaultPaymentTokenAccount: vaultAta,
+      paymentTokenProgram: TOKEN_PROGRAM_ID,
+    };
+    const computeIx = ComputeBudgetProgram.setComputeUnitLimit({ units: 400_000 });
+    const buyIx = await program.methods
+      .buyToken(bn(eventId), digitalAccessId, null, null, null, bn(MAX_PRICE))
+      .accounts({
+        tokenProgram: TOKEN_PROGRAM_ID,
+        eventCreator: wallet.publicKey,
+        buyerPaymentTokenAccount: buyerAta.address,
+        ...paymentAccounts,
+      })
+      .instruction();
+    await provider.sendAndConfirm(new Transaction().add(computeIx, buyIx));
+
+    expect(Number((await getAccount(connection, vaultAta)).amount)).to.equal(price);
+
+    await program.methods.cancelEvent(bn(eventId)).rpc();
+
+    // digital accesses, then the payment mint, vault, creator and token program of each mint
+    const remainingAccounts = [
+      digitalAccessPda,
+      splPaymentMint,
+      vaultAta,
+      buyerAta.address,
+      TOKEN_PROGRAM_ID,
+    ].map((pubkey) => ({ pubkey, isSigner: false, isWritable: true }));
+
+    try {
+      const tx = await program.methods
+        .closeEvent(bn(eventId))
+        .remainingAccounts(remainingAccounts)
+        .rpc();
+      console.error("Unexpected close event tx signature:", tx);
+      return expect.fail("Unexpected close event tx signature:", tx);
+    } catch (err) {
+      console.log("Expected error closing an event with tickets:", err?.error?.errorCode?.code);
+      expect(err.error.errorCode.code).to.equal("TicketsOutstanding");
+    }
+
+    await program.methods
+      .claimRefund(bn(eventId), bn(nftId))
+      .accounts({
+        tokenProgram: TOKEN_PROGRAM_ID,
+        holderPaymentTokenAccount: buyerAta.address,
+        ...paymentAccounts,
+      })
+      .rpc();
+
+    const closeTx = await program.methods
+      .closeEvent(bn(eventId))
+      .remainingAccounts(remainingAccounts)
+      .rpc();
+    console.log("Close escrowed event tx signature:", closeTx);
+
+    expect(await connection.getAccountInfo(eventPda)).to.be.null;
+    expect(await connection.getAccountInfo(vaultAta)).to.be.null;
+    expect(Number((await getAccount(connection, buyerAta.address)).amount)).to.equal(
+      buyerPrevBalance + price
+    );
+  });
+
+  it("Should refund a ticket to make room for the waitlist and mint the seat again!", async () => {
+    const eventId = 8;
+    const digitalAccessId = 0;
+    const nftId = 0;
+    const price = 0.1 * LAMPORTS_PER_SOL;
+    const now = Math.floor(Date.now() / 1000);
+    const uri =
+      "https://raw.githubusercontent.com/franRappazzini/boltick-contracts/main/tests/utils/uri-test.json";
+
+    await program.methods
+      .initializeEvent(
+        "Waitlist Refund Event",
+        "WR",
+        uri,
+        "Waitlist Refund Event",
+        bn(now + DAY),
+        bn(now + 2 * DAY),
+        bn(now - 60),
+        bn(now + DAY),
+        true,
+        0,
+        [],
+        null
+      )
+      .accounts({ tokenProgram: TOKEN_PROGRAM_ID })
+      .rpc();
+
+    await program.methods
+      .addDigitalAccess(
+        bn(eventId),
+        bn(price),
+        bn(1),
+        "General",
+        "GA",
+        "General",
+        uri,
+        null,
+        null,
+        null,
+        true,
+        null
+      )
+      .accounts({ eventRole: null })
+      .rpc();
+
+    const [eventPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from(SEED_EVENT), bn(eventId).toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+    const [vaultPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from(SEED_VAULT), eventPda.toBuffer()],
+      program.programId
+    );
+    const [digitalAccessPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from(SEED_DIGITAL_ACCESS), eventPda.toBuffer(), Buffer.from([digitalAccessId])],
+      program.programId
+    );
+    const vaultPrevBalance = await connection.getBalance(vaultPda);
+
+    const computeIx = ComputeBudgetProgram.setComputeUnitLimit({ units: 400_000 });
+    const buyIx = await program.methods
+      .buyToken(bn(eventId), digitalAccessId, null, null, null, bn(MAX_PRICE))
+      .accounts({ tokenProgram: TOKEN_PROGRAM_ID, eventCreator: wallet.publicKey })
+      .instruction();
+    await provider.sendAndConfirm(new Transaction().add(computeIx, buyIx));
+
+    try {
+      const tx = await program.methods
+        .claimRefund(bn(eventId), bn(nftId))
+        .accounts({ tokenProgram: TOKEN_PROGRAM_ID })
+        .rpc();
+      console.error("Unexpected claim refund tx signature:", tx);
+      return expect.fail("Unexpected claim refund tx signature:", tx);
+    } catch (err) {
+      console.log("Expected error refunding without a waitlist:", err?.error?.errorCode?.code);
+      expect(err.error.errorCode.code).to.equal("RefundNotAvailable");
+    }
+
+    await program.methods
+      .joinWaitlist(bn(eventId), digitalAccessId)
+      .accounts({ buyer: randomKeypair.publicKey })
+      .signers([randomKeypair])
+      .rpc();
+
+    const refundTx = await program.methods
+      .claimRefund(bn(eventId), bn(nftId))
+      .accounts({ tokenProgram: TOKEN_PROGRAM_ID })
+      .rpc();
+    console.log("Claim refund for the waitlist tx signature:", refundTx);
+
+    let digitalAccessAccount = await program.account.digitalAccess.fetch(digitalAccessPda);
+
+    expect(digitalAccessAccount.currentMinted.toNumber()).to.equal(0);
+    expect(await connection.getBalance(vaultPda)).to.equal(vaultPrevBalance);
+
+    const processIx = await program.methods
+      .processWaitlist(bn(eventId), digitalAccessId, bn(0))
+      .accounts({
+        tokenProgram: TOKEN_PROGRAM_ID,
+        eventCreator: wallet.publicKey,
+        buyer: randomKeypair.publicKey,
+      })
+      .instruction();
+    const processTx = await provider.sendAndConfirm(new Transaction().add(computeIx, processIx));
+    console.log("Process waitlist after a refund tx signature:", processTx);
+
+    const eventAccount = await program.account.event.fetch(eventPda);
+    const [nftPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from(SEED_TOKEN_MINT),
+        eventAccount.collectionMintAccount.toBuffer(),
+        bn(nftId + 1).toArrayLike(Buffer, "le", 8),
+      ],
+      program.programId
+    );
+    const tokenAccount = await getAccount(
+      connection,
+      getAssociatedTokenAddressSync(nftPda, randomKeypair.publicKey)
+    );
+    digitalAccessAccount = await program.account.digitalAccess.fetch(digitalAccessPda);
+
+    expect(Number(tokenAccount.amount)).to.equal(1);
+    expect(digitalAccessAccount.currentMinted.toNumber()).to.equal(1);
+    expect(digitalAccessAccount.waitlistLen.toNumber()).to.equal(0);
+    expect(eventAccount.openTickets.toNumber()).to.equal(1);
+    expect(await connection.getBalance(vaultPda)).to.equal(vaultPrevBalance + price);
+  });
+
+  it("Should update token metadata!", async () => {
+    const eventId = 0;
+    const nftId = 0;
+
+    const uri =
+      "https://raw.githubusercontent.com/franRappazzini/boltick-contracts/main/tests/utils/uri-test-update.json";
+
+    // 10' to wait for the transaction to check old metadata in explorer
+    setTimeout(async () => {
+      const tx = await program.methods
+        .updateTokenMetadata(bn(eventId), bn(nftId), uri)
+        // .accounts({})
+        .rpc({ skipPreflight: true });
+
+      console.log("Update token metadata tx signature:", tx);
+    }, 10000);
+  });
+});
+
+const DAY = 24 * 60 * 60;
+// upper bound accepted by the buyer when the test doesn't check the price
+const MAX_PRICE = LAMPORTS_PER_SOL;
+
+function sha256(...buffers: Buffer[]) {
+  return createHash("sha256").update(Buffer.concat(buffers)).digest();
+}
+
+function keccak(...buffers: Buffer[]) {
+  return Buffer.from(keccak_256(Buffer.concat(buffers)));
+}
 
 function bn(n: number) {
   return new anchor.BN(n);
@@ -9,3 +9,21 @@ export const SEED_DIGITAL_ACCESS = "digital_access";
 export const TOKEN_METADATA_PROGRAM_ID = new PublicKey(
   "metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s" // devnet and mainnet
 );
+export const SEED_EVENT_ROLE = "event_role";
+export const SEED_REDEMPTION = "redemption";
+export const SEED_VAULT = "vault";
+export const SEED_TICKET = "ticket";
+export const SEED_LISTING = "listing";
+export const SEED_PRESALE_CLAIM = "presale_claim";
+export const SEED_BUYER_RECORD = "buyer_record";
+export const SEED_DISCOUNT = "discount";
+export const SEED_WAITLIST_ENTRY = "waitlist_entry";
+export const BUBBLEGUM_PROGRAM_ID = new PublicKey(
+  "BGUMAp9Gq7iTEuizy4pqaxsTyUCBK68MDfK752saRPUY" // devnet and mainnet
+);
+export const ACCOUNT_COMPRESSION_PROGRAM_ID = new PublicKey(
+  "cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK" // devnet and mainnet
+);
+export const NOOP_PROGRAM_ID = new PublicKey(
+  "noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV" // devnet and mainnet
+);